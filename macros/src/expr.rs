@@ -0,0 +1,333 @@
+//! The infix parser behind [`expr!`](crate::expr).
+//!
+//! Expressions are translated token by token into the postfix form accepted by
+//! `rpn!`, which then does the actual expansion.
+
+use crate::flatten;
+use crate::parse_literal;
+use crate::punct;
+use crate::unary;
+use crate::MAX_UNARY;
+
+use proc_macro::Delimiter;
+use proc_macro::Group;
+use proc_macro::Ident;
+use proc_macro::Literal;
+use proc_macro::Span;
+use proc_macro::TokenStream;
+use proc_macro::TokenTree;
+
+type Error = (&'static str, Span);
+
+/// The largest numeral understood by `rpn!`.
+const MAX_NUMERAL: u128 = 10;
+
+/// Functions and their arity. Each becomes the `rpn!` operator of the same name.
+const FUNCTIONS: [(&str, usize); 7] = [
+    ("abs", 1),
+    ("fract", 2),
+    ("gcd", 2),
+    ("int", 1),
+    ("inv", 1),
+    ("lcm", 2),
+    ("simplify", 1),
+];
+
+/// Translates an infix expression into the arguments of an `rpn!` invocation.
+pub fn to_rpn(krate: &[TokenTree], input: TokenStream) -> Result<Vec<TokenTree>, Error> {
+    let mut parser = Parser::new(krate, input);
+    if parser.tokens.is_empty() {
+        return Err(("expected an expression", Span::call_site()));
+    }
+    let mut output = Vec::new();
+    parser.sum(&mut output)?;
+    parser.finish()?;
+    Ok(output)
+}
+
+struct Parser<'a> {
+    /// The path to `peano_axioms`.
+    krate: &'a [TokenTree],
+    tokens: Vec<TokenTree>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(krate: &'a [TokenTree], input: TokenStream) -> Self {
+        Self {
+            krate,
+            tokens: flatten(input),
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&TokenTree> {
+        self.tokens.get(self.position)
+    }
+
+    fn peek_punct(&self) -> Option<char> {
+        match self.peek() {
+            Some(TokenTree::Punct(punct)) => Some(punct.as_char()),
+            _ => None,
+        }
+    }
+
+    fn next(&mut self) -> Option<TokenTree> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn end_span(&self) -> Span {
+        self.tokens
+            .last()
+            .map_or_else(Span::call_site, TokenTree::span)
+    }
+
+    /// Fails if any tokens are left over.
+    fn finish(&self) -> Result<(), Error> {
+        self.peek()
+            .map_or(Ok(()), |token| Err(("expected an operator", token.span())))
+    }
+
+    /// `product (('+' | '-') product)*`
+    fn sum(&mut self, output: &mut Vec<TokenTree>) -> Result<(), Error> {
+        self.product(output)?;
+        while let Some(op @ ('+' | '-')) = self.peek_punct() {
+            let span = self
+                .next()
+                .map_or_else(Span::call_site, |token| token.span());
+            self.product(output)?;
+            output.push(punct(op, span));
+        }
+        Ok(())
+    }
+
+    /// `negation (('*' | '/' | '%') negation)*`
+    fn product(&mut self, output: &mut Vec<TokenTree>) -> Result<(), Error> {
+        self.negation(output)?;
+        while let Some(op @ ('*' | '/' | '%')) = self.peek_punct() {
+            let span = self
+                .next()
+                .map_or_else(Span::call_site, |token| token.span());
+            self.negation(output)?;
+            output.push(punct(op, span));
+        }
+        Ok(())
+    }
+
+    /// `'-' negation | power`
+    fn negation(&mut self, output: &mut Vec<TokenTree>) -> Result<(), Error> {
+        if self.peek_punct() == Some('-') {
+            let span = self
+                .next()
+                .map_or_else(Span::call_site, |token| token.span());
+            self.negation(output)?;
+            output.push(punct('~', span));
+            Ok(())
+        } else {
+            self.power(output)
+        }
+    }
+
+    /// `operand ('^' negation)?`
+    ///
+    /// The exponent may itself be a power, making `^` right-associative.
+    fn power(&mut self, output: &mut Vec<TokenTree>) -> Result<(), Error> {
+        self.operand(output)?;
+        if self.peek_punct() == Some('^') {
+            let span = self
+                .next()
+                .map_or_else(Span::call_site, |token| token.span());
+            self.negation(output)?;
+            output.push(punct('^', span));
+        }
+        Ok(())
+    }
+
+    /// An integer literal, a parenthesised expression or type, a function call
+    /// or a type path.
+    fn operand(&mut self, output: &mut Vec<TokenTree>) -> Result<(), Error> {
+        match self.peek() {
+            Some(TokenTree::Literal(_)) => self.literal(output),
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                let group = group.clone();
+                self.position += 1;
+                parenthesised(self.krate, &group, output);
+                Ok(())
+            }
+            Some(TokenTree::Ident(ident)) if self.is_call() => {
+                let ident = ident.clone();
+                self.position += 1;
+                self.call(&ident, output)
+            }
+            Some(TokenTree::Ident(_)) => self.path(output),
+            Some(TokenTree::Punct(punct)) if matches!(punct.as_char(), ':' | '<') => {
+                self.path(output)
+            }
+            Some(token) => Err(("expected an operand", token.span())),
+            None => Err(("expected an operand", self.end_span())),
+        }
+    }
+
+    fn literal(&mut self, output: &mut Vec<TokenTree>) -> Result<(), Error> {
+        let Some(TokenTree::Literal(literal)) = self.next() else {
+            return Err(("expected an integer literal", self.end_span()));
+        };
+        let span = literal.span();
+
+        // Negative literals passed through `macro_rules` fragments are a single token.
+        let text = literal.to_string();
+        let (negative, text) = text
+            .strip_prefix('-')
+            .map_or((false, text.as_str()), |text| (true, text));
+        let magnitude = parse_literal(text).ok_or(("expected an integer literal", span))?;
+
+        if magnitude <= MAX_NUMERAL {
+            let mut numeral = Literal::u128_unsuffixed(magnitude);
+            numeral.set_span(span);
+            output.push(TokenTree::Literal(numeral));
+        } else if magnitude <= MAX_UNARY {
+            output.push(parenthesise(
+                unary(self.krate, false, magnitude, span),
+                span,
+            ));
+        } else {
+            return Err((
+                "literal is too large for a unary number; use a type operand instead",
+                span,
+            ));
+        }
+
+        if negative {
+            output.push(punct('~', span));
+        }
+        Ok(())
+    }
+
+    /// Whether the next tokens are a known function followed by its arguments.
+    fn is_call(&self) -> bool {
+        let is_function = matches!(
+            self.peek(),
+            Some(TokenTree::Ident(ident)) if arity(&ident.to_string()).is_some()
+        );
+        let has_arguments = matches!(
+            self.tokens.get(self.position + 1),
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis
+        );
+        is_function && has_arguments
+    }
+
+    fn call(&mut self, function: &Ident, output: &mut Vec<TokenTree>) -> Result<(), Error> {
+        let Some(TokenTree::Group(group)) = self.next() else {
+            return Err(("expected function arguments", function.span()));
+        };
+        let name = function.to_string();
+        let arity = arity(&name).unwrap_or_default();
+
+        let mut arguments = Self::new(self.krate, group.stream());
+        for index in 0..arity {
+            if index > 0 {
+                match arguments.next() {
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => {}
+                    Some(token) => return Err(("expected `,`", token.span())),
+                    None => return Err(("too few arguments", group.span_close())),
+                }
+            }
+            if arguments.peek().is_none() {
+                return Err(("too few arguments", group.span_close()));
+            }
+            arguments.sum(output)?;
+        }
+        if arguments.peek_punct() == Some(',') {
+            arguments.position += 1;
+        }
+        if let Some(token) = arguments.peek() {
+            return Err(("too many arguments", token.span()));
+        }
+
+        output.push(TokenTree::Ident(Ident::new(&name, function.span())));
+        Ok(())
+    }
+
+    /// A type path such as `T`, `Next<T>` or `<T as Trait>::Result`.
+    fn path(&mut self, output: &mut Vec<TokenTree>) -> Result<(), Error> {
+        let start = self.position;
+        let span = self.peek().map_or_else(Span::call_site, TokenTree::span);
+
+        // Whether the path can be continued by an identifier, rather than `::` or `<`.
+        let mut needs_segment = true;
+        loop {
+            match self.peek() {
+                Some(TokenTree::Ident(_)) if needs_segment => {
+                    self.position += 1;
+                    needs_segment = false;
+                }
+                Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => {
+                    self.position += 1;
+                    needs_segment = true;
+                }
+                Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {
+                    self.angle_brackets()?;
+                    needs_segment = false;
+                }
+                _ => break,
+            }
+        }
+
+        let tokens = self.tokens[start..self.position].to_vec();
+        output.push(parenthesise(tokens, span));
+        Ok(())
+    }
+
+    /// Skips a balanced pair of angle brackets and everything between them.
+    fn angle_brackets(&mut self) -> Result<(), Error> {
+        let open = self.peek().map_or_else(Span::call_site, TokenTree::span);
+        let mut depth = 0_usize;
+        while let Some(token) = self.next() {
+            match token {
+                TokenTree::Punct(punct) if punct.as_char() == '<' => depth += 1,
+                // Skip the `>` of `->` in function pointer types.
+                TokenTree::Punct(punct)
+                    if punct.as_char() == '-' && self.peek_punct() == Some('>') =>
+                {
+                    self.position += 1;
+                }
+                TokenTree::Punct(punct) if punct.as_char() == '>' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Err(("unclosed `<`", open))
+    }
+}
+
+/// Parses the contents of a parenthesised group as an expression, falling
+/// back to treating the whole group as a type.
+fn parenthesised(krate: &[TokenTree], group: &Group, output: &mut Vec<TokenTree>) {
+    let mut parser = Parser::new(krate, group.stream());
+    let mut inner = Vec::new();
+    if !parser.tokens.is_empty() && parser.sum(&mut inner).is_ok() && parser.finish().is_ok() {
+        output.extend(inner);
+    } else {
+        output.push(TokenTree::Group(group.clone()));
+    }
+}
+
+fn arity(function: &str) -> Option<usize> {
+    FUNCTIONS
+        .iter()
+        .find(|&&(name, _)| name == function)
+        .map(|&(_, arity)| arity)
+}
+
+/// Wraps `tokens` in parentheses, so `rpn!` treats them as a single operand.
+fn parenthesise(tokens: Vec<TokenTree>, span: Span) -> TokenTree {
+    let mut group = Group::new(Delimiter::Parenthesis, tokens.into_iter().collect());
+    group.set_span(span);
+    TokenTree::Group(group)
+}
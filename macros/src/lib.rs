@@ -0,0 +1,259 @@
+//! Procedural macros for the `peano-axioms` crate.
+//!
+//! These are re-exported from `peano-axioms` when its `macros` feature is
+//! enabled, and should be used from there.
+
+// Proc macros are only ever called by the compiler, so inlining is meaningless.
+#![allow(clippy::missing_inline_in_public_items)]
+
+mod expr;
+
+use proc_macro::Delimiter;
+use proc_macro::Group;
+use proc_macro::Ident;
+use proc_macro::Literal;
+use proc_macro::Punct;
+use proc_macro::Spacing;
+use proc_macro::Span;
+use proc_macro::TokenStream;
+use proc_macro::TokenTree;
+
+/// The largest magnitude accepted by `peano!`.
+///
+/// This only guards against expansions large enough to exhaust the compiler's
+/// memory. Using unary numbers anywhere near this large already requires a
+/// much higher `recursion_limit` than the default.
+const MAX_UNARY: u128 = u16::MAX as u128;
+
+/// Implementation of `peano_axioms::peano!`.
+///
+/// The first token must be the path to `peano_axioms` in parentheses, which
+/// the wrapper macro passes as `($crate)`.
+#[proc_macro]
+pub fn peano(input: TokenStream) -> TokenStream {
+    let (krate, input) = match split_crate(input) {
+        Ok(split) => split,
+        Err((message, span)) => return error(message, span),
+    };
+    match parse_integer(input) {
+        Ok((negative, magnitude, span)) if magnitude > MAX_UNARY => error(
+            &format!(
+                "{}{magnitude} is too large for a unary number; use `peano_binary!` instead",
+                if negative { "-" } else { "" },
+            ),
+            span,
+        ),
+        Ok((negative, magnitude, span)) => unary(&krate, negative, magnitude, span)
+            .into_iter()
+            .collect(),
+        Err((message, span)) => error(message, span),
+    }
+}
+
+/// Implementation of `peano_axioms::peano_binary!`.
+///
+/// The first token must be the path to `peano_axioms` in parentheses, which
+/// the wrapper macro passes as `($crate)`.
+#[proc_macro]
+pub fn peano_binary(input: TokenStream) -> TokenStream {
+    let (krate, input) = match split_crate(input) {
+        Ok(split) => split,
+        Err((message, span)) => return error(message, span),
+    };
+    let (negative, magnitude, span) = match parse_integer(input) {
+        Ok(parsed) => parsed,
+        Err((message, span)) => return error(message, span),
+    };
+    let value = match i128::try_from(magnitude) {
+        Ok(value) if negative => -value,
+        Ok(value) => value,
+        Err(_) if negative && magnitude == i128::MIN.unsigned_abs() => i128::MIN,
+        Err(_) => return error("literal out of range for `i128`", span),
+    };
+
+    let mut tokens = Vec::new();
+    let mut rest = value;
+    let mut depth = 0;
+    while rest != 0 && rest != -1 {
+        tokens.extend(crate_path(
+            &krate,
+            if rest & 1 == 0 { "B0" } else { "B1" },
+            span,
+        ));
+        tokens.push(punct('<', span));
+        rest >>= 1;
+        depth += 1;
+    }
+    tokens.extend(crate_path(
+        &krate,
+        if rest == 0 { "Zero" } else { "Ones" },
+        span,
+    ));
+    for _ in 0..depth {
+        tokens.push(punct('>', span));
+    }
+    tokens.into_iter().collect()
+}
+
+/// Implementation of `peano_axioms::expr!`.
+///
+/// The first token must be the path to `peano_axioms` in parentheses, which
+/// the wrapper macro passes as `($crate)`.
+#[proc_macro]
+pub fn expr(input: TokenStream) -> TokenStream {
+    let (krate, input) = match split_crate(input) {
+        Ok(split) => split,
+        Err((message, span)) => return error(message, span),
+    };
+    let span = Span::call_site();
+    match expr::to_rpn(&krate, input) {
+        Ok(tokens) => {
+            let mut arguments = Group::new(Delimiter::Parenthesis, tokens.into_iter().collect());
+            arguments.set_span(span);
+            let mut output = crate_path(&krate, "rpn", span);
+            output.extend([punct('!', span), TokenTree::Group(arguments)]);
+            output.into_iter().collect()
+        }
+        Err((message, span)) => error(message, span),
+    }
+}
+
+/// Splits the parenthesised path to `peano_axioms` from the rest of the input.
+fn split_crate(input: TokenStream) -> Result<(Vec<TokenTree>, TokenStream), (&'static str, Span)> {
+    let mut tokens = input.into_iter();
+    match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            Ok((group.stream().into_iter().collect(), tokens.collect()))
+        }
+        _ => Err((
+            "this macro must be called through its re-export in `peano_axioms`",
+            Span::call_site(),
+        )),
+    }
+}
+
+/// The tokens of the canonical unary number with the given sign and magnitude.
+fn unary(krate: &[TokenTree], negative: bool, magnitude: u128, span: Span) -> Vec<TokenTree> {
+    let name = if negative { "Prev" } else { "Next" };
+    let mut tokens = Vec::new();
+    for _ in 0..magnitude {
+        tokens.extend(crate_path(krate, name, span));
+        tokens.push(punct('<', span));
+    }
+    tokens.extend(crate_path(krate, "Zero", span));
+    for _ in 0..magnitude {
+        tokens.push(punct('>', span));
+    }
+    tokens
+}
+
+/// Parses an optionally negated integer literal into its sign and magnitude.
+fn parse_integer(input: TokenStream) -> Result<(bool, u128, Span), (&'static str, Span)> {
+    let mut tokens = flatten(input).into_iter().peekable();
+    let mut negative =
+        matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == '-');
+    if negative {
+        tokens.next();
+    }
+
+    let literal = match tokens.next() {
+        Some(TokenTree::Literal(literal)) => literal,
+        Some(token) => return Err(("expected an integer literal", token.span())),
+        None => return Err(("expected an integer literal", Span::call_site())),
+    };
+    if let Some(token) = tokens.next() {
+        return Err(("unexpected token after integer literal", token.span()));
+    }
+
+    // Negative literals passed through `macro_rules` fragments are a single token.
+    let text = literal.to_string();
+    let text = match text.strip_prefix('-') {
+        Some(text) if !negative => {
+            negative = true;
+            text
+        }
+        _ => &text,
+    };
+
+    parse_literal(text)
+        .map(|magnitude| (negative, magnitude, literal.span()))
+        .ok_or_else(|| ("expected an integer literal", literal.span()))
+}
+
+/// Removes invisible groups, which wrap tokens passed through `macro_rules` fragments.
+fn flatten(input: TokenStream) -> Vec<TokenTree> {
+    input
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                flatten(group.stream())
+            }
+            token => vec![token],
+        })
+        .collect()
+}
+
+/// Parses the text of an integer literal, including any prefix, separators or suffix.
+fn parse_literal(text: &str) -> Option<u128> {
+    const SUFFIXES: [&str; 12] = [
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+    ];
+
+    let (radix, digits) = match text.get(..2) {
+        Some("0x") => (16, &text[2..]),
+        Some("0o") => (8, &text[2..]),
+        Some("0b") => (2, &text[2..]),
+        _ => (10, text),
+    };
+    let digits = SUFFIXES
+        .iter()
+        .find_map(|suffix| digits.strip_suffix(suffix))
+        .unwrap_or(digits);
+    let digits: String = digits.chars().filter(|&c| c != '_').collect();
+
+    u128::from_str_radix(&digits, radix).ok()
+}
+
+/// The tokens `$krate::$name`.
+fn crate_path(krate: &[TokenTree], name: &str, span: Span) -> Vec<TokenTree> {
+    let mut tokens = krate.to_vec();
+    tokens.extend([
+        joint_punct(':', span),
+        punct(':', span),
+        TokenTree::Ident(Ident::new(name, span)),
+    ]);
+    tokens
+}
+
+fn punct(ch: char, span: Span) -> TokenTree {
+    let mut punct = Punct::new(ch, Spacing::Alone);
+    punct.set_span(span);
+    TokenTree::Punct(punct)
+}
+
+fn joint_punct(ch: char, span: Span) -> TokenTree {
+    let mut punct = Punct::new(ch, Spacing::Joint);
+    punct.set_span(span);
+    TokenTree::Punct(punct)
+}
+
+/// The tokens `::core::compile_error!($message)`.
+fn error(message: &str, span: Span) -> TokenStream {
+    let mut literal = Literal::string(message);
+    literal.set_span(span);
+    let mut group = Group::new(Delimiter::Parenthesis, TokenTree::Literal(literal).into());
+    group.set_span(span);
+
+    [
+        joint_punct(':', span),
+        punct(':', span),
+        TokenTree::Ident(Ident::new("core", span)),
+        joint_punct(':', span),
+        punct(':', span),
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        punct('!', span),
+        TokenTree::Group(group),
+    ]
+    .into_iter()
+    .collect()
+}
@@ -0,0 +1,1093 @@
+use crate::cmp::OrderingPredicates;
+use crate::cmp::SignOrdering;
+use crate::cmp::SignOrderingOf;
+use crate::div::Unit;
+use crate::Abs;
+use crate::Absolute;
+use crate::Compare;
+use crate::Conditional;
+use crate::Equal;
+use crate::ExclusiveDisjunction;
+use crate::Exp;
+use crate::False;
+use crate::Fraction;
+use crate::Gcd;
+use crate::Greater;
+use crate::If;
+use crate::Inverse;
+use crate::IsLess;
+use crate::IsNegative;
+use crate::Lcm;
+use crate::Less;
+use crate::Negation;
+use crate::Next;
+use crate::NonNegative;
+use crate::NonPositive;
+use crate::NonZero;
+use crate::One;
+use crate::Ordering;
+use crate::Positive;
+use crate::Prev;
+use crate::Product;
+use crate::Quotient;
+use crate::Reciprocal;
+use crate::Reify;
+use crate::Sequence;
+use crate::Sign;
+use crate::Signum;
+use crate::Simplified;
+use crate::Simplify;
+use crate::Sum;
+use crate::True;
+use crate::Xor;
+use crate::Zero;
+
+use core::fmt;
+use core::hash;
+use core::marker::PhantomData;
+use core::ops::Add;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Rem;
+use core::ops::Sub;
+
+use local_type_alias::local_alias;
+
+/// The binary number `-1`, i.e. an infinite sequence of one bits.
+///
+/// Binary numbers are stored in two's complement, least significant bit
+/// first, and are terminated by either [`Zero`] (an infinite sequence of zero
+/// bits) or `Ones`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Ones;
+
+impl Ones {
+    /// The value associated with this type, i.e. -1.
+    pub const VALUE: Ones = Ones;
+}
+
+/// The binary number `2 * T`, i.e. `T` followed by a zero bit.
+///
+/// Arithmetic on binary numbers requires that they are canonical, i.e. that
+/// there is no `B0<Zero>` or `B1<Ones>`. If this causes errors, the
+/// [`Simplify`] trait can be used to remove redundancies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct B0<T>(PhantomData<T>);
+
+impl<T> B0<T> {
+    /// The value associated with this type.
+    pub const VALUE: B0<T> = B0(PhantomData);
+}
+
+/// The binary number `2 * T + 1`, i.e. `T` followed by a one bit.
+///
+/// Arithmetic on binary numbers requires that they are canonical, i.e. that
+/// there is no `B0<Zero>` or `B1<Ones>`. If this causes errors, the
+/// [`Simplify`] trait can be used to remove redundancies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct B1<T>(PhantomData<T>);
+
+impl<T> B1<T> {
+    /// The value associated with this type.
+    pub const VALUE: B1<T> = B1(PhantomData);
+}
+
+impl fmt::Display for Ones {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("-1")
+    }
+}
+
+impl hash::Hash for Ones {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        state.write_i128(-1);
+    }
+}
+
+macro_rules! impl_fmt_hash {
+    ($($name:ident),*) => {
+        $(
+            impl<T> fmt::Display for $name<T>
+            where
+                Self: Reify<i128>,
+            {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", Self::REIFIED)
+                }
+            }
+
+            impl<T> hash::Hash for $name<T>
+            where
+                Self: Reify<i128>,
+            {
+                #[inline]
+                fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                    state.write_i128(Self::REIFIED);
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt_hash![B0, B1];
+
+macro_rules! reify_ones {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Reify<$ty> for Ones {
+                const REIFIED: $ty = -1;
+            }
+
+            impl From<Ones> for $ty {
+                #[inline(always)]
+                fn from(_: Ones) -> Self {
+                    -1
+                }
+            }
+        )*
+    };
+}
+
+reify_ones![i8, i16, i32, i64, i128, isize];
+
+macro_rules! reify_bit {
+    ($name:ident, $bit:literal, $($ty:ty),* $(,)?) => {
+        $(
+            impl<T> Reify<$ty> for $name<T>
+            where
+                T: Reify<$ty>,
+            {
+                const REIFIED: $ty = T::REIFIED * 2 + $bit;
+            }
+
+            impl<T> From<$name<T>> for $ty
+            where
+                T: Reify<$ty>,
+            {
+                #[inline(always)]
+                fn from(_: $name<T>) -> $ty {
+                    <$name<T> as Reify<$ty>>::REIFIED
+                }
+            }
+        )*
+    };
+}
+
+reify_bit![B0, 0, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+reify_bit![B1, 1, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+pub trait Push0 {
+    type Result;
+}
+
+impl Push0 for Zero {
+    type Result = Zero;
+}
+
+impl Push0 for Ones {
+    type Result = B0<Ones>;
+}
+
+impl<T> Push0 for B0<T> {
+    type Result = B0<Self>;
+}
+
+impl<T> Push0 for B1<T> {
+    type Result = B0<Self>;
+}
+
+/// `2 * T`.
+pub type Doubled<T> = <T as Push0>::Result;
+
+pub trait Push1 {
+    type Result;
+}
+
+impl Push1 for Zero {
+    type Result = B1<Zero>;
+}
+
+impl Push1 for Ones {
+    type Result = Ones;
+}
+
+impl<T> Push1 for B0<T> {
+    type Result = B1<Self>;
+}
+
+impl<T> Push1 for B1<T> {
+    type Result = B1<Self>;
+}
+
+/// `2 * T + 1`.
+pub type DoubledPlusOne<T> = <T as Push1>::Result;
+
+pub trait Increment {
+    type Result;
+}
+
+impl Increment for Zero {
+    type Result = B1<Zero>;
+}
+
+impl Increment for Ones {
+    type Result = Zero;
+}
+
+impl<T> Increment for B0<T> {
+    type Result = B1<T>;
+}
+
+impl<T> Increment for B1<T>
+where
+    T: Increment,
+    Incremented<T>: Push0,
+{
+    type Result = Doubled<Incremented<T>>;
+}
+
+pub type Incremented<T> = <T as Increment>::Result;
+
+pub trait Decrement {
+    type Result;
+}
+
+impl Decrement for Zero {
+    type Result = Ones;
+}
+
+impl Decrement for Ones {
+    type Result = B0<Ones>;
+}
+
+impl<T> Decrement for B0<T>
+where
+    T: Decrement,
+    Decremented<T>: Push1,
+{
+    type Result = DoubledPlusOne<Decremented<T>>;
+}
+
+impl<T> Decrement for B1<T>
+where
+    T: Push0,
+{
+    type Result = Doubled<T>;
+}
+
+pub type Decremented<T> = <T as Decrement>::Result;
+
+pub trait Flip {
+    type Result;
+}
+
+impl Flip for Zero {
+    type Result = Ones;
+}
+
+impl Flip for Ones {
+    type Result = Zero;
+}
+
+impl<T: Flip> Flip for B0<T> {
+    type Result = B1<Flipped<T>>;
+}
+
+impl<T: Flip> Flip for B1<T> {
+    type Result = B0<Flipped<T>>;
+}
+
+/// The bitwise complement of `T`, i.e. `-T - 1`.
+pub type Flipped<T> = <T as Flip>::Result;
+
+impl<T> Sequence for B0<T>
+where
+    Self: Decrement,
+{
+    type Next = B1<T>;
+
+    type Prev = Decremented<Self>;
+}
+
+impl<T> Sequence for B1<T>
+where
+    Self: Increment,
+    T: Push0,
+{
+    type Next = Incremented<Self>;
+
+    type Prev = Doubled<T>;
+}
+
+impl Sequence for Ones {
+    type Next = Zero;
+
+    type Prev = B0<Ones>;
+}
+
+/// Full addition of canonical binary numbers: `Self + U + Carry`.
+pub trait BinaryAdd<U, Carry> {
+    type Result;
+}
+
+pub type BinarySum<T, U, Carry> = <T as BinaryAdd<U, Carry>>::Result;
+
+impl<U> BinaryAdd<U, False> for Zero {
+    type Result = U;
+}
+
+impl<U: Increment> BinaryAdd<U, True> for Zero {
+    type Result = Incremented<U>;
+}
+
+impl<U: Decrement> BinaryAdd<U, False> for Ones {
+    type Result = Decremented<U>;
+}
+
+impl<U> BinaryAdd<U, True> for Ones {
+    type Result = U;
+}
+
+macro_rules! impl_binary_add_terminator {
+    ($($name:ident),*) => {
+        $(
+            impl<T> BinaryAdd<Zero, False> for $name<T> {
+                type Result = Self;
+            }
+
+            impl<T> BinaryAdd<Zero, True> for $name<T>
+            where
+                Self: Increment,
+            {
+                type Result = Incremented<Self>;
+            }
+
+            impl<T> BinaryAdd<Ones, False> for $name<T>
+            where
+                Self: Decrement,
+            {
+                type Result = Decremented<Self>;
+            }
+
+            impl<T> BinaryAdd<Ones, True> for $name<T> {
+                type Result = Self;
+            }
+        )*
+    };
+}
+
+impl_binary_add_terminator![B0, B1];
+
+macro_rules! impl_binary_add_bits {
+    ($($lhs:ident, $rhs:ident, $carry:ty => $push:ident, $next_carry:ty;)*) => {
+        $(
+            impl<T, U> BinaryAdd<$rhs<U>, $carry> for $lhs<T>
+            where
+                T: BinaryAdd<U, $next_carry>,
+                BinarySum<T, U, $next_carry>: $push,
+            {
+                type Result = <BinarySum<T, U, $next_carry> as $push>::Result;
+            }
+        )*
+    };
+}
+
+impl_binary_add_bits! {
+    B0, B0, False => Push0, False;
+    B0, B0, True => Push1, False;
+    B0, B1, False => Push1, False;
+    B0, B1, True => Push0, True;
+    B1, B0, False => Push1, False;
+    B1, B0, True => Push0, True;
+    B1, B1, False => Push0, True;
+    B1, B1, True => Push1, True;
+}
+
+/// Subtraction of canonical binary numbers.
+pub trait BinarySub<U> {
+    type Result;
+}
+
+impl<T, U> BinarySub<U> for T
+where
+    U: Flip,
+    T: BinaryAdd<Flipped<U>, True>,
+{
+    type Result = BinarySum<T, Flipped<U>, True>;
+}
+
+/// Multiplication of canonical binary numbers.
+pub trait BinaryMul<U> {
+    type Result;
+}
+
+impl<U> BinaryMul<U> for Zero {
+    type Result = Zero;
+}
+
+impl<U> BinaryMul<U> for Ones
+where
+    U: Flip,
+    Flipped<U>: Increment,
+{
+    type Result = Incremented<Flipped<U>>;
+}
+
+impl<T, U> BinaryMul<U> for B0<T>
+where
+    T: BinaryMul<U>,
+    <T as BinaryMul<U>>::Result: Push0,
+{
+    type Result = Doubled<<T as BinaryMul<U>>::Result>;
+}
+
+impl<T, U> BinaryMul<U> for B1<T>
+where
+    T: BinaryMul<U>,
+    <T as BinaryMul<U>>::Result: Push0,
+    Doubled<<T as BinaryMul<U>>::Result>: BinaryAdd<U, False>,
+{
+    type Result = BinarySum<Doubled<<T as BinaryMul<U>>::Result>, U, False>;
+}
+
+/// Division with remainder of a non-negative canonical binary number by a
+/// positive one.
+pub trait BinaryDivMod<U> {
+    type Quotient;
+    type Remainder;
+}
+
+impl<U> BinaryDivMod<U> for Zero {
+    type Quotient = Zero;
+    type Remainder = Zero;
+}
+
+macro_rules! impl_binary_div_mod {
+    ($($name:ident => $push:ident),*) => {
+        $(
+            #[local_alias]
+            #[alias(
+                type Q = <T as BinaryDivMod<U>>::Quotient,
+                type R = <<T as BinaryDivMod<U>>::Remainder as $push>::Result,
+            )]
+            impl<T, U> BinaryDivMod<U> for $name<T>
+            where
+                T: BinaryDivMod<U>,
+                <T as BinaryDivMod<U>>::Remainder: $push,
+                R: Compare<U>,
+                Ordering<R, U>: OrderingPredicates,
+                IsLess<R, U>: DivModStep<Q, R, U>,
+            {
+                type Quotient = <IsLess<R, U> as DivModStep<Q, R, U>>::Quotient;
+                type Remainder = <IsLess<R, U> as DivModStep<Q, R, U>>::Remainder;
+            }
+        )*
+    };
+}
+
+impl_binary_div_mod![B0 => Push0, B1 => Push1];
+
+pub trait DivModStep<Q, R, U> {
+    type Quotient;
+    type Remainder;
+}
+
+impl<Q: Push0, R, U> DivModStep<Q, R, U> for True {
+    type Quotient = Doubled<Q>;
+    type Remainder = R;
+}
+
+impl<Q: Push1, R, U> DivModStep<Q, R, U> for False
+where
+    R: Sub<U>,
+{
+    type Quotient = DoubledPlusOne<Q>;
+    type Remainder = <R as Sub<U>>::Output;
+}
+
+/// Truncating division with remainder of canonical binary numbers.
+pub trait BinaryDivRem<U> {
+    type Quotient;
+    type Remainder;
+}
+
+#[local_alias]
+#[alias(
+    type Q = <Absolute<T> as BinaryDivMod<Absolute<U>>>::Quotient,
+    type R = <Absolute<T> as BinaryDivMod<Absolute<U>>>::Remainder,
+    type OppositeSigns = ExclusiveDisjunction<IsNegative<T>, IsNegative<U>>,
+)]
+impl<T, U> BinaryDivRem<U> for T
+where
+    T: Abs + Compare<Zero>,
+    U: Abs + Compare<Zero> + NonZero,
+    Absolute<T>: BinaryDivMod<Absolute<U>>,
+    Ordering<T, Zero>: OrderingPredicates,
+    Ordering<U, Zero>: OrderingPredicates,
+    IsNegative<T>: Xor<IsNegative<U>> + Conditional<Negation<R>, R>,
+    OppositeSigns: Conditional<Negation<Q>, Q>,
+    Q: Neg,
+    R: Neg,
+{
+    type Quotient = If<OppositeSigns, Negation<Q>, Q>;
+    type Remainder = If<IsNegative<T>, Negation<R>, R>;
+}
+
+/// The non-negative greatest common divisor of `Self` and `T`, both of which
+/// must be non-negative.
+pub trait BinaryEuclid<T> {
+    type Result;
+}
+
+impl<T> BinaryEuclid<T> for Zero {
+    type Result = T;
+}
+
+macro_rules! impl_binary_euclid {
+    ($($name:ident),*) => {
+        $(
+            impl<T, U> BinaryEuclid<T> for $name<U>
+            where
+                T: Rem<Self>,
+                <T as Rem<Self>>::Output: BinaryEuclid<Self>,
+            {
+                type Result = <<T as Rem<Self>>::Output as BinaryEuclid<Self>>::Result;
+            }
+        )*
+    };
+}
+
+impl_binary_euclid![B0, B1];
+
+/// Exponentiation of canonical binary numbers by squaring.
+///
+/// `Self` is the exponent, which must be non-negative, and `T` is the base.
+pub trait BinaryPowerOf<T> {
+    type Result;
+}
+
+impl<T> BinaryPowerOf<T> for Zero {
+    type Result = B1<Zero>;
+}
+
+#[local_alias]
+#[alias(type Half = <K as BinaryPowerOf<T>>::Result)]
+impl<T, K> BinaryPowerOf<T> for B0<K>
+where
+    K: BinaryPowerOf<T>,
+    Half: BinaryMul<Half>,
+{
+    type Result = <Half as BinaryMul<Half>>::Result;
+}
+
+#[local_alias]
+#[alias(
+    type Half = <K as BinaryPowerOf<T>>::Result,
+    type HalfSquared = <Half as BinaryMul<Half>>::Result,
+)]
+impl<T, K> BinaryPowerOf<T> for B1<K>
+where
+    K: BinaryPowerOf<T>,
+    Half: BinaryMul<Half>,
+    HalfSquared: BinaryMul<T>,
+{
+    type Result = <HalfSquared as BinaryMul<T>>::Result;
+}
+
+/// Exponentiation of a canonical binary base `T` by `E`, where `Self` is the
+/// sign of `E`.
+///
+/// Negative exponents give the reciprocal of the base raised to `-E`.
+pub trait BinaryExp<T, E> {
+    type Result;
+}
+
+impl<T, E> BinaryExp<T, E> for Less
+where
+    E: Neg,
+    Negation<E>: BinaryPowerOf<T>,
+    <Negation<E> as BinaryPowerOf<T>>::Result: Inverse,
+{
+    type Result = Reciprocal<<Negation<E> as BinaryPowerOf<T>>::Result>;
+}
+
+impl<T, E> BinaryExp<T, E> for Equal
+where
+    E: BinaryPowerOf<T>,
+{
+    type Result = <E as BinaryPowerOf<T>>::Result;
+}
+
+impl<T, E> BinaryExp<T, E> for Greater
+where
+    E: BinaryPowerOf<T>,
+{
+    type Result = <E as BinaryPowerOf<T>>::Result;
+}
+
+macro_rules! impl_binary_ops {
+    ($(<$($param:ident),*> $lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl<$($param),*> Add<$rhs> for $lhs
+            where
+                $lhs: BinaryAdd<$rhs, False>,
+                BinarySum<$lhs, $rhs, False>: Default,
+            {
+                type Output = BinarySum<$lhs, $rhs, False>;
+
+                #[inline(always)]
+                fn add(self, _: $rhs) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+
+            impl<$($param),*> Sub<$rhs> for $lhs
+            where
+                $lhs: BinarySub<$rhs>,
+                <$lhs as BinarySub<$rhs>>::Result: Default,
+            {
+                type Output = <$lhs as BinarySub<$rhs>>::Result;
+
+                #[inline(always)]
+                fn sub(self, _: $rhs) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+
+            impl<$($param),*> Mul<$rhs> for $lhs
+            where
+                $lhs: BinaryMul<$rhs>,
+                <$lhs as BinaryMul<$rhs>>::Result: Default,
+            {
+                type Output = <$lhs as BinaryMul<$rhs>>::Result;
+
+                #[inline(always)]
+                fn mul(self, _: $rhs) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+
+            impl<$($param),*> Gcd<$rhs> for $lhs
+            where
+                $lhs: Abs,
+                $rhs: Abs,
+                Absolute<$rhs>: BinaryEuclid<Absolute<$lhs>>,
+            {
+                type Result = <Absolute<$rhs> as BinaryEuclid<Absolute<$lhs>>>::Result;
+            }
+        )*
+    };
+}
+
+impl_binary_ops![
+    <> Zero, Ones;
+    <U> Zero, B0<U>;
+    <U> Zero, B1<U>;
+    <> Ones, Zero;
+    <> Ones, Ones;
+    <U> Ones, B0<U>;
+    <U> Ones, B1<U>;
+    <T> B0<T>, Zero;
+    <T> B0<T>, Ones;
+    <T, U> B0<T>, B0<U>;
+    <T, U> B0<T>, B1<U>;
+    <T> B1<T>, Zero;
+    <T> B1<T>, Ones;
+    <T, U> B1<T>, B0<U>;
+    <T, U> B1<T>, B1<U>;
+];
+
+macro_rules! impl_binary_div {
+    ($(<$($param:ident),*> $lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl<$($param),*> Div<$rhs> for $lhs
+            where
+                $lhs: BinaryDivRem<$rhs>,
+                <$lhs as BinaryDivRem<$rhs>>::Quotient: Default,
+            {
+                type Output = <$lhs as BinaryDivRem<$rhs>>::Quotient;
+
+                #[inline(always)]
+                fn div(self, _: $rhs) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+
+            impl<$($param),*> Rem<$rhs> for $lhs
+            where
+                $lhs: BinaryDivRem<$rhs>,
+                <$lhs as BinaryDivRem<$rhs>>::Remainder: Default,
+            {
+                type Output = <$lhs as BinaryDivRem<$rhs>>::Remainder;
+
+                #[inline(always)]
+                fn rem(self, _: $rhs) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+
+            impl<$($param),*> Lcm<$rhs> for $lhs
+            where
+                $lhs: Mul<$rhs> + Gcd<$rhs>,
+                Product<$lhs, $rhs>: Abs,
+                Absolute<Product<$lhs, $rhs>>: Div<<$lhs as Gcd<$rhs>>::Result>,
+            {
+                type Result = Quotient<Absolute<Product<$lhs, $rhs>>, <$lhs as Gcd<$rhs>>::Result>;
+            }
+        )*
+    };
+}
+
+impl_binary_div![
+    <> Zero, Ones;
+    <U> Zero, B0<U>;
+    <U> Zero, B1<U>;
+    <> Ones, Ones;
+    <U> Ones, B0<U>;
+    <U> Ones, B1<U>;
+    <T> B0<T>, Ones;
+    <T, U> B0<T>, B0<U>;
+    <T, U> B0<T>, B1<U>;
+    <T> B1<T>, Ones;
+    <T, U> B1<T>, B0<U>;
+    <T, U> B1<T>, B1<U>;
+];
+
+macro_rules! impl_binary_lcm_zero {
+    ($(<$($param:ident),*> $lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl<$($param),*> Lcm<$rhs> for $lhs {
+                type Result = Zero;
+            }
+        )*
+    };
+}
+
+impl_binary_lcm_zero![
+    <> Ones, Zero;
+    <T> B0<T>, Zero;
+    <T> B1<T>, Zero;
+];
+
+impl Neg for Ones {
+    type Output = B1<Zero>;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self::Output::VALUE
+    }
+}
+
+macro_rules! impl_binary_unary {
+    ($($name:ident),*) => {
+        $(
+            impl<T> Neg for $name<T>
+            where
+                Self: Flip,
+                Flipped<Self>: Increment,
+                Incremented<Flipped<Self>>: Default,
+            {
+                type Output = Incremented<Flipped<Self>>;
+
+                #[inline(always)]
+                fn neg(self) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+
+            impl<T, E> Exp<E> for $name<T>
+            where
+                E: SignOrdering,
+                SignOrderingOf<E>: BinaryExp<Self, E>,
+            {
+                type Result = <SignOrderingOf<E> as BinaryExp<Self, E>>::Result;
+            }
+
+            impl<T> Inverse for $name<T>
+            where
+                Self: NonZero + Abs + Sign,
+                Absolute<Self>: Positive,
+            {
+                type Result = Fraction<Signum<Self>, Absolute<Self>>;
+            }
+
+            impl<T> Abs for $name<T>
+            where
+                Self: Compare<Zero> + Neg,
+                Ordering<Self, Zero>: OrderingPredicates,
+                IsNegative<Self>: Conditional<Negation<Self>, Self>,
+                If<IsNegative<Self>, Negation<Self>, Self>: NonNegative,
+            {
+                type Result = If<IsNegative<Self>, Negation<Self>, Self>;
+            }
+
+            impl<T> Sign for $name<T>
+            where
+                Self: SignOrdering,
+                SignOrderingOf<Self>: BinaryOrderingSign,
+            {
+                type Result = <SignOrderingOf<Self> as BinaryOrderingSign>::Result;
+            }
+        )*
+    };
+}
+
+impl_binary_unary![B0, B1];
+
+impl<E> Exp<E> for Ones
+where
+    E: SignOrdering,
+    SignOrderingOf<E>: BinaryExp<Self, E>,
+{
+    type Result = <SignOrderingOf<E> as BinaryExp<Self, E>>::Result;
+}
+
+impl Inverse for Ones {
+    type Result = Fraction<Ones, B1<Zero>>;
+}
+
+impl<K> Exp<B0<K>> for Zero
+where
+    B0<K>: Positive,
+{
+    type Result = Zero;
+}
+
+impl<K> Exp<B1<K>> for Zero
+where
+    B1<K>: Positive,
+{
+    type Result = Zero;
+}
+
+impl Abs for Ones {
+    type Result = B1<Zero>;
+}
+
+impl Sign for Ones {
+    type Result = Self;
+}
+
+impl Unit for Ones {
+    type Result = B1<Zero>;
+}
+
+impl<T> Unit for B0<T> {
+    type Result = B1<Zero>;
+}
+
+impl<T> Unit for B1<T> {
+    type Result = B1<Zero>;
+}
+
+/// The binary sign corresponding to an ordering relative to zero.
+pub trait BinaryOrderingSign {
+    type Result;
+}
+
+impl BinaryOrderingSign for Less {
+    type Result = Ones;
+}
+
+impl BinaryOrderingSign for Equal {
+    type Result = Zero;
+}
+
+impl BinaryOrderingSign for Greater {
+    type Result = B1<Zero>;
+}
+
+impl Simplify for Ones {
+    type Result = Self;
+}
+
+impl<T> Simplify for B0<T>
+where
+    T: Simplify,
+    Simplified<T>: Push0,
+{
+    type Result = Doubled<Simplified<T>>;
+}
+
+impl<T> Simplify for B1<T>
+where
+    T: Simplify,
+    Simplified<T>: Push1,
+{
+    type Result = DoubledPlusOne<Simplified<T>>;
+}
+
+impl SignOrdering for Ones {
+    type Result = Less;
+}
+
+impl<T> SignOrdering for B0<T>
+where
+    T: SignOrdering,
+{
+    type Result = SignOrderingOf<T>;
+}
+
+impl<T> SignOrdering for B1<T>
+where
+    T: SignOrdering,
+    SignOrderingOf<T>: OddSignOrdering,
+{
+    type Result = <SignOrderingOf<T> as OddSignOrdering>::Result;
+}
+
+/// The sign of `2 * T + 1` given the sign of `T`.
+pub trait OddSignOrdering {
+    type Result;
+}
+
+impl OddSignOrdering for Less {
+    type Result = Less;
+}
+
+impl OddSignOrdering for Equal {
+    type Result = Greater;
+}
+
+impl OddSignOrdering for Greater {
+    type Result = Greater;
+}
+
+impl<T> NonNegative for B0<T> where T: NonNegative {}
+
+impl<T> NonNegative for B1<T> where T: NonNegative {}
+
+impl NonPositive for Ones {}
+
+impl<T> NonPositive for B0<T> where T: NonPositive {}
+
+impl<T> NonPositive for B1<T> where T: NonPositive + NonZero {}
+
+impl NonZero for Ones {}
+
+impl<T> NonZero for B0<T> where T: NonZero {}
+
+impl<T> NonZero for B1<T> {}
+
+/// Conversion to the binary representation.
+pub trait IntoBinary {
+    /// The binary representation of `Self`.
+    type Result;
+}
+
+impl IntoBinary for Zero {
+    type Result = Zero;
+}
+
+impl<T> IntoBinary for Next<T>
+where
+    T: IntoBinary,
+    ToBinary<T>: Increment,
+{
+    type Result = Incremented<ToBinary<T>>;
+}
+
+impl<T> IntoBinary for Prev<T>
+where
+    T: IntoBinary,
+    ToBinary<T>: Decrement,
+{
+    type Result = Decremented<ToBinary<T>>;
+}
+
+impl IntoBinary for Ones {
+    type Result = Ones;
+}
+
+impl<T> IntoBinary for B0<T>
+where
+    Self: Simplify,
+{
+    type Result = Simplified<Self>;
+}
+
+impl<T> IntoBinary for B1<T>
+where
+    Self: Simplify,
+{
+    type Result = Simplified<Self>;
+}
+
+impl<Num, Dem> IntoBinary for Fraction<Num, Dem>
+where
+    Num: IntoBinary,
+    Dem: IntoBinary + Positive,
+    ToBinary<Dem>: Positive,
+{
+    type Result = Fraction<ToBinary<Num>, ToBinary<Dem>>;
+}
+
+/// The binary representation of `T`.
+///
+/// This is the identity on binary numbers, and converts the numerator and
+/// denominator of a [`Fraction`] separately.
+pub type ToBinary<T> = <T as IntoBinary>::Result;
+
+/// Conversion to the unary representation.
+pub trait IntoUnary {
+    /// The unary representation of `Self`.
+    type Result;
+}
+
+impl IntoUnary for Zero {
+    type Result = Zero;
+}
+
+impl<T> IntoUnary for Next<T>
+where
+    Self: Simplify,
+{
+    type Result = Simplified<Self>;
+}
+
+impl<T> IntoUnary for Prev<T>
+where
+    Self: Simplify,
+{
+    type Result = Simplified<Self>;
+}
+
+impl IntoUnary for Ones {
+    type Result = Prev<Zero>;
+}
+
+impl<T> IntoUnary for B0<T>
+where
+    T: IntoUnary,
+    ToUnary<T>: Add<ToUnary<T>>,
+{
+    type Result = Sum<ToUnary<T>, ToUnary<T>>;
+}
+
+impl<T> IntoUnary for B1<T>
+where
+    T: IntoUnary,
+    ToUnary<T>: Add<ToUnary<T>>,
+    Sum<ToUnary<T>, ToUnary<T>>: Add<One>,
+{
+    type Result = Sum<Sum<ToUnary<T>, ToUnary<T>>, One>;
+}
+
+impl<Num, Dem> IntoUnary for Fraction<Num, Dem>
+where
+    Num: IntoUnary,
+    Dem: IntoUnary + Positive,
+    ToUnary<Dem>: Positive,
+{
+    type Result = Fraction<ToUnary<Num>, ToUnary<Dem>>;
+}
+
+/// The canonical unary representation of `T`.
+///
+/// This simplifies unary numbers, and converts the numerator and denominator
+/// of a [`Fraction`] separately.
+pub type ToUnary<T> = <T as IntoUnary>::Result;
@@ -0,0 +1,279 @@
+use crate::cmp::OrderingPredicates;
+use crate::root::PowerOf;
+use crate::Compare;
+use crate::Difference;
+use crate::DivEuclid;
+use crate::DivFloor;
+use crate::EuclidQuotient;
+use crate::EuclidRemainder;
+use crate::False;
+use crate::FloorQuotient;
+use crate::IsEqual;
+use crate::Next;
+use crate::NonNegative;
+use crate::One;
+use crate::Ordering;
+use crate::Prev;
+use crate::Product;
+use crate::Simplified;
+use crate::Simplify;
+use crate::Sum;
+use crate::True;
+use crate::Two;
+use crate::Zero;
+
+use core::ops::Add;
+use core::ops::BitAnd;
+use core::ops::BitOr;
+use core::ops::BitXor;
+use core::ops::Mul;
+use core::ops::Shl;
+use core::ops::Shr;
+use core::ops::Sub;
+
+use local_type_alias::local_alias;
+
+pub struct AndOp;
+
+pub struct OrOp;
+
+pub struct XorOp;
+
+pub trait BitTable<A, B> {
+    type Result;
+}
+
+macro_rules! impl_bit_table {
+    ($($op:ty: $a:ty, $b:ty => $result:ty;)*) => {
+        $(
+            impl BitTable<$a, $b> for $op {
+                type Result = $result;
+            }
+        )*
+    };
+}
+
+impl_bit_table! {
+    AndOp: Zero, Zero => Zero;
+    AndOp: Zero, One => Zero;
+    AndOp: One, Zero => Zero;
+    AndOp: One, One => One;
+    OrOp: Zero, Zero => Zero;
+    OrOp: Zero, One => One;
+    OrOp: One, Zero => One;
+    OrOp: One, One => One;
+    XorOp: Zero, Zero => Zero;
+    XorOp: Zero, One => One;
+    XorOp: One, Zero => One;
+    XorOp: One, One => Zero;
+}
+
+/// Bitwise operations where `Self` is either `0` or `-1`, i.e. has no bits
+/// other than its infinitely repeated sign bit.
+pub trait BitBase<U, Op> {
+    type Result;
+}
+
+impl<U> BitBase<U, AndOp> for Zero {
+    type Result = Zero;
+}
+
+impl<U> BitBase<U, OrOp> for Zero {
+    type Result = U;
+}
+
+impl<U> BitBase<U, XorOp> for Zero {
+    type Result = U;
+}
+
+impl<U> BitBase<U, AndOp> for Prev<Zero> {
+    type Result = U;
+}
+
+impl<U> BitBase<U, OrOp> for Prev<Zero> {
+    type Result = Self;
+}
+
+impl<U> BitBase<U, XorOp> for Prev<Zero>
+where
+    Self: Sub<U>,
+{
+    type Result = Difference<Self, U>;
+}
+
+/// Bitwise operations on canonical numbers.
+pub trait Bitwise<U, Op> {
+    type Result;
+}
+
+impl<T, U, Op> Bitwise<U, Op> for T
+where
+    T: DivEuclid<Two>,
+    EuclidQuotient<T, Two>: Compare<T>,
+    Ordering<EuclidQuotient<T, Two>, T>: OrderingPredicates,
+    IsEqual<EuclidQuotient<T, Two>, T>: BitwiseStep<T, U, Op>,
+{
+    type Result = <IsEqual<EuclidQuotient<T, Two>, T> as BitwiseStep<T, U, Op>>::Result;
+}
+
+pub trait BitwiseStep<T, U, Op> {
+    type Result;
+}
+
+impl<T, U, Op> BitwiseStep<T, U, Op> for True
+where
+    T: BitBase<U, Op>,
+{
+    type Result = <T as BitBase<U, Op>>::Result;
+}
+
+#[local_alias]
+#[alias(
+    type High = <EuclidQuotient<T, Two> as Bitwise<EuclidQuotient<U, Two>, Op>>::Result,
+    type Low = <Op as BitTable<EuclidRemainder<T, Two>, EuclidRemainder<U, Two>>>::Result,
+)]
+impl<T, U, Op> BitwiseStep<T, U, Op> for False
+where
+    T: DivEuclid<Two>,
+    U: DivEuclid<Two>,
+    EuclidQuotient<T, Two>: Bitwise<EuclidQuotient<U, Two>, Op>,
+    Op: BitTable<EuclidRemainder<T, Two>, EuclidRemainder<U, Two>>,
+    Two: Mul<High>,
+    Product<Two, High>: Add<Low>,
+{
+    type Result = Sum<Product<Two, High>, Low>;
+}
+
+pub trait SimplifiedBitwise<U, Op> {
+    type Result;
+}
+
+impl<T, U, Op> SimplifiedBitwise<U, Op> for T
+where
+    T: Simplify,
+    U: Simplify,
+    Simplified<T>: Bitwise<Simplified<U>, Op>,
+{
+    type Result = <Simplified<T> as Bitwise<Simplified<U>, Op>>::Result;
+}
+
+macro_rules! impl_bitwise {
+    ($trait:ident, $method:ident, $op:ty) => {
+        impl_bitwise! {
+            @rows $trait, $method, $op;
+            <> Zero, Zero;
+            <U> Zero, Next<U>;
+            <U> Zero, Prev<U>;
+            <T> Next<T>, Zero;
+            <T> Prev<T>, Zero;
+            <T, U> Next<T>, Next<U>;
+            <T, U> Next<T>, Prev<U>;
+            <T, U> Prev<T>, Next<U>;
+            <T, U> Prev<T>, Prev<U>;
+        }
+    };
+    (@rows $trait:ident, $method:ident, $op:ty; $(<$($param:ident),*> $lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl<$($param),*> $trait<$rhs> for $lhs
+            where
+                $lhs: SimplifiedBitwise<$rhs, $op>,
+                <$lhs as SimplifiedBitwise<$rhs, $op>>::Result: Default,
+            {
+                type Output = <$lhs as SimplifiedBitwise<$rhs, $op>>::Result;
+
+                #[inline(always)]
+                fn $method(self, _: $rhs) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+        )*
+    };
+}
+
+impl_bitwise!(BitAnd, bitand, AndOp);
+impl_bitwise!(BitOr, bitor, OrOp);
+impl_bitwise!(BitXor, bitxor, XorOp);
+
+/// The bitwise conjunction of `T` and `U`, using two's complement for negative numbers.
+pub type BitwiseAnd<T, U> = <T as BitAnd<U>>::Output;
+
+/// The bitwise disjunction of `T` and `U`, using two's complement for negative numbers.
+pub type BitwiseOr<T, U> = <T as BitOr<U>>::Output;
+
+/// The bitwise exclusive disjunction of `T` and `U`, using two's complement for negative
+/// numbers.
+pub type BitwiseXor<T, U> = <T as BitXor<U>>::Output;
+
+pub trait SimplifiedShift<U> {
+    type Left;
+    type Right;
+}
+
+#[local_alias]
+#[alias(type Scale = <Simplified<U> as PowerOf<Two>>::Result)]
+impl<T, U> SimplifiedShift<U> for T
+where
+    T: Simplify,
+    U: Simplify + NonNegative,
+    Simplified<U>: PowerOf<Two>,
+    Simplified<T>: Mul<Scale<U>> + DivFloor<Scale<U>>,
+{
+    type Left = Product<Simplified<T>, Scale<U>>;
+
+    type Right = FloorQuotient<Simplified<T>, Scale<U>>;
+}
+
+macro_rules! impl_shift {
+    ($(<$($param:ident),*> $lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl<$($param),*> Shl<$rhs> for $lhs
+            where
+                $lhs: SimplifiedShift<$rhs>,
+                <$lhs as SimplifiedShift<$rhs>>::Left: Default,
+            {
+                type Output = <$lhs as SimplifiedShift<$rhs>>::Left;
+
+                #[inline(always)]
+                fn shl(self, _: $rhs) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+
+            impl<$($param),*> Shr<$rhs> for $lhs
+            where
+                $lhs: SimplifiedShift<$rhs>,
+                <$lhs as SimplifiedShift<$rhs>>::Right: Default,
+            {
+                type Output = <$lhs as SimplifiedShift<$rhs>>::Right;
+
+                #[inline(always)]
+                fn shr(self, _: $rhs) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+        )*
+    };
+}
+
+impl_shift![
+    <> Zero, Zero;
+    <U> Zero, Next<U>;
+    <U> Zero, Prev<U>;
+    <T> Next<T>, Zero;
+    <T> Prev<T>, Zero;
+    <T, U> Next<T>, Next<U>;
+    <T, U> Next<T>, Prev<U>;
+    <T, U> Prev<T>, Next<U>;
+    <T, U> Prev<T>, Prev<U>;
+];
+
+/// `T` shifted left by `U` bits, i.e. `T * 2^U`.
+///
+/// Only implemented for non-negative `U`.
+pub type LeftShift<T, U> = <T as Shl<U>>::Output;
+
+/// `T` arithmetically shifted right by `U` bits, i.e. `T / 2^U` rounded towards negative
+/// infinity.
+///
+/// Only implemented for non-negative `U`.
+pub type RightShift<T, U> = <T as Shr<U>>::Output;
@@ -0,0 +1,263 @@
+use crate::Complement;
+use crate::Conditional;
+use crate::Difference;
+use crate::False;
+use crate::If;
+use crate::Next;
+use crate::Ones;
+use crate::Prev;
+use crate::Reify;
+use crate::Simplified;
+use crate::Simplify;
+use crate::True;
+use crate::Zero;
+use crate::B0;
+use crate::B1;
+
+use core::cmp;
+use core::ops::Sub;
+
+/// The result of a comparison where the first number is less than the second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Less;
+
+impl Less {
+    /// The value associated with this type.
+    pub const VALUE: Less = Less;
+}
+
+impl Reify<cmp::Ordering> for Less {
+    const REIFIED: cmp::Ordering = cmp::Ordering::Less;
+}
+
+/// The result of a comparison where both numbers are equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Equal;
+
+impl Equal {
+    /// The value associated with this type.
+    pub const VALUE: Equal = Equal;
+}
+
+impl Reify<cmp::Ordering> for Equal {
+    const REIFIED: cmp::Ordering = cmp::Ordering::Equal;
+}
+
+/// The result of a comparison where the first number is greater than the second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Greater;
+
+impl Greater {
+    /// The value associated with this type.
+    pub const VALUE: Greater = Greater;
+}
+
+impl Reify<cmp::Ordering> for Greater {
+    const REIFIED: cmp::Ordering = cmp::Ordering::Greater;
+}
+
+pub trait OrderingPredicates {
+    type IsLess;
+    type IsEqual;
+    type IsGreater;
+}
+
+impl OrderingPredicates for Less {
+    type IsLess = True;
+    type IsEqual = False;
+    type IsGreater = False;
+}
+
+impl OrderingPredicates for Equal {
+    type IsLess = False;
+    type IsEqual = True;
+    type IsGreater = False;
+}
+
+impl OrderingPredicates for Greater {
+    type IsLess = False;
+    type IsEqual = False;
+    type IsGreater = True;
+}
+
+pub trait SignOrdering {
+    type Result;
+}
+
+impl SignOrdering for Zero {
+    type Result = Equal;
+}
+
+impl<T> SignOrdering for Next<T> {
+    type Result = Greater;
+}
+
+impl<T> SignOrdering for Prev<T> {
+    type Result = Less;
+}
+
+pub type SignOrderingOf<T> = <T as SignOrdering>::Result;
+
+pub trait OrderingSign {
+    type Result;
+}
+
+impl OrderingSign for Less {
+    type Result = Prev<Zero>;
+}
+
+impl OrderingSign for Equal {
+    type Result = Zero;
+}
+
+impl OrderingSign for Greater {
+    type Result = Next<Zero>;
+}
+
+/// Type-level comparison.
+pub trait Compare<T> {
+    /// The ordering of `Self` relative to `T`; one of [`Less`], [`Equal`] or [`Greater`].
+    type Result;
+}
+
+macro_rules! impl_compare {
+    ($(<$($param:ident),*> $lhs:ty, $rhs:ty);* $(;)?) => {
+        $(
+            impl<$($param),*> Compare<$rhs> for $lhs
+            where
+                $lhs: Sub<$rhs>,
+                Difference<$lhs, $rhs>: Simplify,
+                Simplified<Difference<$lhs, $rhs>>: SignOrdering,
+            {
+                type Result = SignOrderingOf<Simplified<Difference<$lhs, $rhs>>>;
+            }
+        )*
+    };
+}
+
+impl_compare![
+    <> Zero, Zero;
+    <U> Zero, Next<U>;
+    <U> Zero, Prev<U>;
+    <T> Next<T>, Zero;
+    <T> Prev<T>, Zero;
+    <T, U> Next<T>, Next<U>;
+    <T, U> Next<T>, Prev<U>;
+    <T, U> Prev<T>, Next<U>;
+    <T, U> Prev<T>, Prev<U>;
+    <> Zero, Ones;
+    <U> Zero, B0<U>;
+    <U> Zero, B1<U>;
+    <> Ones, Zero;
+    <> Ones, Ones;
+    <U> Ones, B0<U>;
+    <U> Ones, B1<U>;
+    <T> B0<T>, Zero;
+    <T> B0<T>, Ones;
+    <T, U> B0<T>, B0<U>;
+    <T, U> B0<T>, B1<U>;
+    <T> B1<T>, Zero;
+    <T> B1<T>, Ones;
+    <T, U> B1<T>, B0<U>;
+    <T, U> B1<T>, B1<U>;
+];
+
+/// The ordering of `T` relative to `U`.
+pub type Ordering<T, U> = <T as Compare<U>>::Result;
+
+/// Whether `T` is less than `U`.
+pub type IsLess<T, U> = <Ordering<T, U> as OrderingPredicates>::IsLess;
+
+/// Whether `T` is equal to `U`.
+pub type IsEqual<T, U> = <Ordering<T, U> as OrderingPredicates>::IsEqual;
+
+/// Whether `T` is greater than `U`.
+pub type IsGreater<T, U> = <Ordering<T, U> as OrderingPredicates>::IsGreater;
+
+/// Whether `T` is less than or equal to `U`.
+pub type IsLessOrEqual<T, U> = Complement<IsGreater<T, U>>;
+
+/// Whether `T` is not equal to `U`.
+pub type IsNotEqual<T, U> = Complement<IsEqual<T, U>>;
+
+/// Whether `T` is greater than or equal to `U`.
+pub type IsGreaterOrEqual<T, U> = Complement<IsLess<T, U>>;
+
+/// Whether `T` is zero.
+pub type IsZero<T> = IsEqual<T, Zero>;
+
+/// Whether `T` is non-zero.
+pub type IsNonZero<T> = IsNotEqual<T, Zero>;
+
+/// Whether `T` is positive.
+pub type IsPositive<T> = IsGreater<T, Zero>;
+
+/// Whether `T` is negative.
+pub type IsNegative<T> = IsLess<T, Zero>;
+
+/// Whether `T` is non-negative.
+pub type IsNonNegative<T> = IsGreaterOrEqual<T, Zero>;
+
+/// Whether `T` is non-positive.
+pub type IsNonPositive<T> = IsLessOrEqual<T, Zero>;
+
+/// Type-level maximum.
+pub trait Max<T> {
+    /// The greater of `Self` and `T`.
+    type Result;
+}
+
+impl<T, U> Max<U> for T
+where
+    T: Compare<U>,
+    Ordering<T, U>: OrderingPredicates,
+    IsLess<T, U>: Conditional<U, T>,
+{
+    type Result = If<IsLess<T, U>, U, T>;
+}
+
+/// The greater of `T` and `U`.
+///
+/// If `T` and `U` are equal, this is `T`.
+pub type Maximum<T, U> = <T as Max<U>>::Result;
+
+/// Type-level minimum.
+pub trait Min<T> {
+    /// The lesser of `Self` and `T`.
+    type Result;
+}
+
+impl<T, U> Min<U> for T
+where
+    T: Compare<U>,
+    Ordering<T, U>: OrderingPredicates,
+    IsGreater<T, U>: Conditional<U, T>,
+{
+    type Result = If<IsGreater<T, U>, U, T>;
+}
+
+/// The lesser of `T` and `U`.
+///
+/// If `T` and `U` are equal, this is `T`.
+pub type Minimum<T, U> = <T as Min<U>>::Result;
+
+/// Type-level restriction to an interval.
+pub trait Clamp<Lo, Hi> {
+    /// `Self` restricted to the interval `[Lo, Hi]`.
+    type Result;
+}
+
+impl<T, Lo, Hi> Clamp<Lo, Hi> for T
+where
+    Lo: Compare<Hi>,
+    Ordering<Lo, Hi>: OrderingPredicates<IsGreater = False>,
+    T: Max<Lo>,
+    Maximum<T, Lo>: Min<Hi>,
+{
+    type Result = Minimum<Maximum<T, Lo>, Hi>;
+}
+
+/// `T` restricted to the interval `[Lo, Hi]`.
+///
+/// Fails to compile if `Lo` is greater than `Hi`.
+pub type Clamped<T, Lo, Hi> = <T as Clamp<Lo, Hi>>::Result;
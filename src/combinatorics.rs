@@ -0,0 +1,128 @@
+use crate::Difference;
+use crate::Next;
+use crate::NonNegative;
+use crate::One;
+use crate::Product;
+use crate::Quotient;
+use crate::Simplified;
+use crate::Simplify;
+use crate::Zero;
+
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Sub;
+
+use local_type_alias::local_alias;
+
+pub trait FactorialOf {
+    type Result;
+}
+
+impl FactorialOf for Zero {
+    type Result = One;
+}
+
+impl<T> FactorialOf for Next<T>
+where
+    T: FactorialOf,
+    Next<T>: Mul<<T as FactorialOf>::Result>,
+{
+    type Result = Product<Next<T>, <T as FactorialOf>::Result>;
+}
+
+/// Type-level factorial.
+pub trait Fact {
+    /// The factorial of `Self`.
+    type Result;
+}
+
+impl<T> Fact for T
+where
+    T: Simplify,
+    Simplified<T>: FactorialOf,
+{
+    type Result = <Simplified<T> as FactorialOf>::Result;
+}
+
+/// The factorial of `T`.
+///
+/// Only implemented for non-negative integers.
+pub type Factorial<T> = <T as Fact>::Result;
+
+pub trait FallingFactorial<T> {
+    type Result;
+}
+
+impl<T> FallingFactorial<T> for Zero {
+    type Result = One;
+}
+
+impl<T, K> FallingFactorial<T> for Next<K>
+where
+    T: Sub<One> + Mul<<K as FallingFactorial<Difference<T, One>>>::Result>,
+    K: FallingFactorial<Difference<T, One>>,
+{
+    type Result = Product<T, <K as FallingFactorial<Difference<T, One>>>::Result>;
+}
+
+/// Type-level count of ordered selections.
+pub trait Permute<K> {
+    /// The number of ways to choose an ordered sequence of `K` items from
+    /// `Self` items.
+    type Result;
+}
+
+impl<T, K> Permute<K> for T
+where
+    T: NonNegative,
+    K: NonNegative + Simplify,
+    Simplified<K>: FallingFactorial<T>,
+{
+    type Result = <Simplified<K> as FallingFactorial<T>>::Result;
+}
+
+/// The number of ways to choose an ordered sequence of `K` items from `T`
+/// items.
+///
+/// This is zero if `K` is greater than `T`.
+pub type Permutations<T, K> = <T as Permute<K>>::Result;
+
+pub trait BinomialOf<T> {
+    type Result;
+}
+
+impl<T> BinomialOf<T> for Zero {
+    type Result = One;
+}
+
+#[local_alias]
+#[alias(type Previous = <K as BinomialOf<T>>::Result)]
+impl<T, K> BinomialOf<T> for Next<K>
+where
+    K: BinomialOf<T>,
+    T: Sub<K>,
+    Previous: Mul<Difference<T, K>>,
+    Product<Previous, Difference<T, K>>: Div<Next<K>>,
+{
+    type Result = Quotient<Product<Previous, Difference<T, K>>, Next<K>>;
+}
+
+/// Type-level binomial coefficients.
+pub trait Choose<K> {
+    /// The number of ways to choose `K` items from `Self` items.
+    type Result;
+}
+
+impl<T, K> Choose<K> for T
+where
+    T: NonNegative,
+    K: NonNegative + Simplify,
+    Simplified<K>: BinomialOf<T>,
+{
+    type Result = <Simplified<K> as BinomialOf<T>>::Result;
+}
+
+/// The number of ways to choose `K` items from `T` items.
+///
+/// This is zero if `K` is greater than `T`.
+pub type Binomial<T, K> = <T as Choose<K>>::Result;
@@ -0,0 +1,82 @@
+use crate::Next;
+use crate::Prev;
+use crate::Zero;
+
+/// A const generic signed integer.
+///
+/// This can be converted to a type-level number with [`ConstToPeano`] for
+/// values between `-128` and `128` inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConstInt<const N: i128>;
+
+impl<const N: i128> ConstInt<N> {
+    /// The value associated with this type.
+    pub const VALUE: Self = ConstInt;
+}
+
+/// A const generic unsigned integer, such as the length of an array.
+///
+/// This can be converted to a type-level number with [`ConstToPeano`] for
+/// values up to and including `128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConstUsize<const N: usize>;
+
+impl<const N: usize> ConstUsize<N> {
+    /// The value associated with this type.
+    pub const VALUE: Self = ConstUsize;
+}
+
+/// Conversion from const generics to type-level numbers.
+pub trait ConstToPeano {
+    /// The canonical type-level number equal to `Self`.
+    type Output;
+}
+
+impl ConstToPeano for ConstInt<0> {
+    type Output = Zero;
+}
+
+impl ConstToPeano for ConstUsize<0> {
+    type Output = Zero;
+}
+
+macro_rules! impl_const_to_peano {
+    ($positive:ty, $negative:ty; $n:literal $(, $rest:literal)* $(,)?) => {
+        impl ConstToPeano for ConstInt<$n> {
+            type Output = $positive;
+        }
+
+        impl ConstToPeano for ConstInt<{ -$n }> {
+            type Output = $negative;
+        }
+
+        impl ConstToPeano for ConstUsize<$n> {
+            type Output = $positive;
+        }
+
+        impl_const_to_peano!(Next<$positive>, Prev<$negative>; $($rest),*);
+    };
+    ($positive:ty, $negative:ty;) => {};
+}
+
+impl_const_to_peano! {
+    Next<Zero>, Prev<Zero>;
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+    49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+    65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80,
+    81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96,
+    97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112,
+    113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128,
+}
+
+/// The type-level number equal to `N`.
+///
+/// Only implemented for values between `-128` and `128` inclusive.
+pub type Int<const N: i128> = <ConstInt<N> as ConstToPeano>::Output;
+
+/// The type-level number equal to `N`.
+///
+/// Only implemented for values up to and including `128`.
+pub type Usize<const N: usize> = <ConstUsize<N> as ConstToPeano>::Output;
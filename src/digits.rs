@@ -0,0 +1,108 @@
+use crate::cmp::OrderingPredicates;
+use crate::Compare;
+use crate::Cons;
+use crate::False;
+use crate::Greater;
+use crate::IsLess;
+use crate::Length;
+use crate::Nil;
+use crate::NonNegative;
+use crate::One;
+use crate::Ordering;
+use crate::Quotient;
+use crate::Remainder;
+use crate::Simplified;
+use crate::Simplify;
+use crate::Sum;
+use crate::True;
+use crate::Zero;
+
+use core::ops::Add;
+use core::ops::Div;
+use core::ops::Rem;
+
+pub trait DigitsFrom<B, Acc> {
+    type Result;
+}
+
+impl<T, B, Acc> DigitsFrom<B, Acc> for T
+where
+    T: Compare<B>,
+    Ordering<T, B>: OrderingPredicates,
+    IsLess<T, B>: DigitStep<T, B, Acc>,
+{
+    type Result = <IsLess<T, B> as DigitStep<T, B, Acc>>::Result;
+}
+
+pub trait DigitStep<T, B, Acc> {
+    type Result;
+}
+
+impl<T, B, Acc> DigitStep<T, B, Acc> for True {
+    type Result = Cons<T, Acc>;
+}
+
+impl<T, B, Acc> DigitStep<T, B, Acc> for False
+where
+    T: Div<B> + Rem<B>,
+    Quotient<T, B>: DigitsFrom<B, Cons<Remainder<T, B>, Acc>>,
+{
+    type Result = <Quotient<T, B> as DigitsFrom<B, Cons<Remainder<T, B>, Acc>>>::Result;
+}
+
+/// Type-level digit decomposition.
+pub trait Decompose<B> {
+    /// The base `B` digits of `Self`, most significant first.
+    type Result;
+}
+
+impl<T, B> Decompose<B> for T
+where
+    T: NonNegative + Simplify,
+    B: Compare<One, Result = Greater>,
+    Simplified<T>: DigitsFrom<B, Nil>,
+{
+    type Result = <Simplified<T> as DigitsFrom<B, Nil>>::Result;
+}
+
+/// The base `B` digits of `T` as a type-level list, most significant first.
+///
+/// Only implemented for non-negative `T` and `B` greater than one. The digits
+/// of zero are a single zero.
+pub type Digits<T, B> = <T as Decompose<B>>::Result;
+
+/// The number of base `B` digits in `T`.
+pub type NumDigits<T, B> = Length<Digits<T, B>>;
+
+pub trait DigitTotal {
+    type Result;
+}
+
+impl DigitTotal for Nil {
+    type Result = Zero;
+}
+
+impl<Head, Tail> DigitTotal for Cons<Head, Tail>
+where
+    Tail: DigitTotal,
+    Head: Add<<Tail as DigitTotal>::Result>,
+{
+    type Result = Sum<Head, <Tail as DigitTotal>::Result>;
+}
+
+/// Type-level digit sum.
+pub trait SumDigits<B> {
+    /// The sum of the base `B` digits of `Self`.
+    type Result;
+}
+
+impl<T, B> SumDigits<B> for T
+where
+    T: Decompose<B>,
+    Digits<T, B>: DigitTotal,
+{
+    type Result = <Digits<T, B> as DigitTotal>::Result;
+}
+
+/// The sum of the base `B` digits of `T`.
+pub type DigitSum<T, B> = <T as SumDigits<B>>::Result;
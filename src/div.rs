@@ -1,12 +1,32 @@
+use crate::cmp::OrderingPredicates;
 use crate::rpn;
+use crate::Abs;
+use crate::Absolute;
+use crate::And;
+use crate::Compare;
+use crate::Complement;
+use crate::Conditional;
+use crate::Conjunction;
 use crate::Difference;
+use crate::ExclusiveDisjunction;
+use crate::If;
+use crate::IsEqual;
+use crate::IsGreaterOrEqual;
+use crate::IsLess;
+use crate::IsNegative;
+use crate::IsNonZero;
 use crate::Negation;
 use crate::Negative;
 use crate::Next;
+use crate::Not;
 use crate::One;
+use crate::Ordering;
 use crate::Positive;
 use crate::Prev;
+use crate::Simplified;
+use crate::Simplify;
 use crate::Sum;
+use crate::Xor;
 use crate::Zero;
 
 use core::ops::Add;
@@ -56,6 +76,21 @@ impl<T: Default, U> Rem<U> for DivHelper<T> {
 
 pub type DivHelperOf<T, Divisor> = <T as ToDivHelper<Divisor>>::Result;
 
+/// The number one, in the same representation as `Self`.
+pub trait Unit {
+    type Result;
+}
+
+impl<T> Unit for Next<T> {
+    type Result = One;
+}
+
+impl<T> Unit for Prev<T> {
+    type Result = One;
+}
+
+pub type UnitOf<T> = <T as Unit>::Result;
+
 impl<T> Div<Next<T>> for Zero {
     type Output = Zero;
 
@@ -134,7 +169,7 @@ where
 impl<T, U> Div<Prev<U>> for Next<T>
 where
     Prev<U>: Neg + Negative,
-    Next<T>: Div<Negation<Prev<U>>> + Rem<Output = Zero> + Positive,
+    Next<T>: Div<Negation<Prev<U>>> + Positive,
     Quotient<Next<T>, Negation<Prev<U>>>: Neg,
     Negation<Quotient<Next<T>, Negation<Prev<U>>>>: Default,
 {
@@ -149,14 +184,14 @@ where
 impl<T, U> Rem<Prev<U>> for Next<T>
 where
     Prev<U>: Neg + Negative,
-    Next<T>: Div<Negation<Prev<U>>> + Rem<Output = Zero> + Positive,
-    Quotient<Next<T>, Negation<Prev<U>>>: Neg,
+    Next<T>: Rem<Negation<Prev<U>>> + Positive,
+    Remainder<Next<T>, Negation<Prev<U>>>: Default,
 {
-    type Output = Zero;
+    type Output = Remainder<Next<T>, Negation<Prev<U>>>;
 
     #[inline(always)]
     fn rem(self, _: Prev<U>) -> Self::Output {
-        Zero
+        Self::Output::default()
     }
 }
 
@@ -164,7 +199,7 @@ impl<T, U> Div<Next<U>> for Prev<T>
 where
     Next<U>: Positive,
     Prev<T>: Neg + Negative,
-    Negation<Prev<T>>: Div<Next<U>> + Rem<Output = Zero>,
+    Negation<Prev<T>>: Div<Next<U>>,
     Quotient<Negation<Prev<T>>, Next<U>>: Neg,
     Negation<Quotient<Negation<Prev<T>>, Next<U>>>: Default,
 {
@@ -180,14 +215,15 @@ impl<T, U> Rem<Next<U>> for Prev<T>
 where
     Next<U>: Positive,
     Prev<T>: Neg + Negative,
-    Negation<Prev<T>>: Div<Next<U>> + Rem<Output = Zero>,
-    Quotient<Negation<Prev<T>>, Next<U>>: Neg,
+    Negation<Prev<T>>: Rem<Next<U>>,
+    Remainder<Negation<Prev<T>>, Next<U>>: Neg,
+    Negation<Remainder<Negation<Prev<T>>, Next<U>>>: Default,
 {
-    type Output = Zero;
+    type Output = Negation<Remainder<Negation<Prev<T>>, Next<U>>>;
 
     #[inline(always)]
     fn rem(self, _: Next<U>) -> Self::Output {
-        Zero
+        Self::Output::default()
     }
 }
 
@@ -195,7 +231,7 @@ impl<T, U> Div<Prev<U>> for Prev<T>
 where
     Prev<T>: Neg + Negative,
     Prev<U>: Neg + Negative,
-    Negation<Prev<T>>: Div<Negation<Prev<U>>> + Rem<Output = Zero>,
+    Negation<Prev<T>>: Div<Negation<Prev<U>>>,
     Quotient<Negation<Prev<T>>, Negation<Prev<U>>>: Default,
 {
     type Output = Quotient<Negation<Prev<T>>, Negation<Prev<U>>>;
@@ -210,18 +246,188 @@ impl<T, U> Rem<Prev<U>> for Prev<T>
 where
     Prev<T>: Neg + Negative,
     Prev<U>: Neg + Negative,
-    Negation<Prev<T>>: Div<Negation<Prev<U>>> + Rem<Output = Zero>,
+    Negation<Prev<T>>: Rem<Negation<Prev<U>>>,
+    Remainder<Negation<Prev<T>>, Negation<Prev<U>>>: Neg,
+    Negation<Remainder<Negation<Prev<T>>, Negation<Prev<U>>>>: Default,
 {
-    type Output = Zero;
+    type Output = Negation<Remainder<Negation<Prev<T>>, Negation<Prev<U>>>>;
 
     #[inline(always)]
     fn rem(self, _: Prev<U>) -> Self::Output {
-        Zero
+        Self::Output::default()
     }
 }
 
 /// The quotient of `T` and `U`.
+///
+/// The quotient is rounded towards zero, matching the behaviour of `/` on
+/// primitive integers.
 pub type Quotient<T, U> = <T as Div<U>>::Output;
 
 /// The remainder when dividing `T` by `U`.
+///
+/// The remainder has the same sign as `T`, matching the behaviour of `%` on
+/// primitive integers.
 pub type Remainder<T, U> = <T as Rem<U>>::Output;
+
+/// Type-level division rounding towards negative infinity.
+pub trait DivFloor<T> {
+    /// The quotient of `Self` and `T`, rounded towards negative infinity.
+    type Result;
+}
+
+#[local_alias]
+#[alias(
+    type N = Simplified<T>,
+    type D = Simplified<U>,
+    type I = UnitOf<D>,
+    type Q = Quotient<N, D>,
+    type R = Remainder<N, D>,
+    type OppositeSigns = ExclusiveDisjunction<IsNegative<R>, IsNegative<D>>,
+)]
+impl<T, U> DivFloor<U> for T
+where
+    T: Simplify,
+    U: Simplify,
+    N: Div<D> + Rem<D>,
+    Q: Sub<I>,
+    R: Compare<Zero>,
+    D: Unit + Compare<Zero>,
+    Ordering<R, Zero>: OrderingPredicates,
+    Ordering<D, Zero>: OrderingPredicates,
+    IsEqual<R, Zero>: Not,
+    IsNegative<R>: Xor<IsNegative<D>>,
+    IsNonZero<R>: And<OppositeSigns>,
+    Conjunction<IsNonZero<R>, OppositeSigns>: Conditional<Difference<Q, I>, Q>,
+{
+    type Result = If<Conjunction<IsNonZero<R>, OppositeSigns>, Difference<Q, I>, Q>;
+}
+
+/// The quotient of `T` and `U`, rounded towards negative infinity.
+pub type FloorQuotient<T, U> = <T as DivFloor<U>>::Result;
+
+/// Type-level division rounding towards positive infinity.
+pub trait DivCeil<T> {
+    /// The quotient of `Self` and `T`, rounded towards positive infinity.
+    type Result;
+}
+
+#[local_alias]
+#[alias(
+    type N = Simplified<T>,
+    type D = Simplified<U>,
+    type I = UnitOf<D>,
+    type Q = Quotient<N, D>,
+    type R = Remainder<N, D>,
+    type OppositeSigns = ExclusiveDisjunction<IsNegative<R>, IsNegative<D>>,
+)]
+impl<T, U> DivCeil<U> for T
+where
+    T: Simplify,
+    U: Simplify,
+    N: Div<D> + Rem<D>,
+    Q: Add<I>,
+    R: Compare<Zero>,
+    D: Unit + Compare<Zero>,
+    Ordering<R, Zero>: OrderingPredicates,
+    Ordering<D, Zero>: OrderingPredicates,
+    IsEqual<R, Zero>: Not,
+    IsNegative<R>: Xor<IsNegative<D>>,
+    OppositeSigns: Not,
+    IsNonZero<R>: And<Complement<OppositeSigns>>,
+    Conjunction<IsNonZero<R>, Complement<OppositeSigns>>: Conditional<Sum<Q, I>, Q>,
+{
+    type Result = If<Conjunction<IsNonZero<R>, Complement<OppositeSigns>>, Sum<Q, I>, Q>;
+}
+
+/// The quotient of `T` and `U`, rounded towards positive infinity.
+pub type CeilQuotient<T, U> = <T as DivCeil<U>>::Result;
+
+/// Type-level division rounding to the nearest integer.
+pub trait DivRound<T> {
+    /// The quotient of `Self` and `T`, rounded to the nearest integer.
+    type Result;
+}
+
+#[local_alias]
+#[alias(
+    type N = Simplified<T>,
+    type D = Simplified<U>,
+    type I = UnitOf<D>,
+    type Q = Quotient<N, D>,
+    type R = Remainder<N, D>,
+    type RoundsAway = IsGreaterOrEqual<Sum<Absolute<R>, Absolute<R>>, Absolute<D>>,
+    type OppositeSigns = ExclusiveDisjunction<IsNegative<N>, IsNegative<D>>,
+    type Rounded = If<OppositeSigns, Difference<Q, I>, Sum<Q, I>>,
+)]
+impl<T, U> DivRound<U> for T
+where
+    T: Simplify,
+    U: Simplify,
+    N: Div<D> + Rem<D> + Compare<Zero>,
+    Q: Add<I> + Sub<I>,
+    R: Abs,
+    D: Unit + Abs + Compare<Zero>,
+    Absolute<R>: Add<Absolute<R>>,
+    Sum<Absolute<R>, Absolute<R>>: Compare<Absolute<D>>,
+    Ordering<Sum<Absolute<R>, Absolute<R>>, Absolute<D>>: OrderingPredicates,
+    IsLess<Sum<Absolute<R>, Absolute<R>>, Absolute<D>>: Not,
+    Ordering<N, Zero>: OrderingPredicates,
+    Ordering<D, Zero>: OrderingPredicates,
+    IsNegative<N>: Xor<IsNegative<D>>,
+    OppositeSigns: Conditional<Difference<Q, I>, Sum<Q, I>>,
+    RoundsAway: Conditional<Rounded, Q>,
+{
+    type Result = If<RoundsAway, Rounded, Q>;
+}
+
+/// The quotient of `T` and `U`, rounded to the nearest integer.
+///
+/// Halfway cases are rounded away from zero.
+pub type RoundQuotient<T, U> = <T as DivRound<U>>::Result;
+
+/// Type-level Euclidean division.
+pub trait DivEuclid<T> {
+    /// The Euclidean quotient of `Self` and `T`.
+    type Quotient;
+
+    /// The Euclidean remainder of `Self` and `T`.
+    type Remainder;
+}
+
+#[local_alias]
+#[alias(
+    type N = Simplified<T>,
+    type D = Simplified<U>,
+    type I = UnitOf<D>,
+    type Q = Quotient<N, D>,
+    type R = Remainder<N, D>,
+    type Adjusted = If<IsNegative<D>, Sum<Q, I>, Difference<Q, I>>,
+)]
+impl<T, U> DivEuclid<U> for T
+where
+    T: Simplify,
+    U: Simplify,
+    N: Div<D> + Rem<D>,
+    Q: Add<I> + Sub<I>,
+    R: Compare<Zero> + Add<Absolute<D>>,
+    D: Unit + Abs + Compare<Zero>,
+    Ordering<R, Zero>: OrderingPredicates,
+    Ordering<D, Zero>: OrderingPredicates,
+    IsNegative<D>: Conditional<Sum<Q, I>, Difference<Q, I>>,
+    IsNegative<R>: Conditional<Adjusted, Q> + Conditional<Sum<R, Absolute<D>>, R>,
+{
+    type Quotient = If<IsNegative<R>, Adjusted, Q>;
+
+    type Remainder = If<IsNegative<R>, Sum<R, Absolute<D>>, R>;
+}
+
+/// The Euclidean quotient of `T` and `U`.
+///
+/// This is the quotient for which [`EuclidRemainder<T, U>`] is non-negative.
+pub type EuclidQuotient<T, U> = <T as DivEuclid<U>>::Quotient;
+
+/// The Euclidean remainder when dividing `T` by `U`.
+///
+/// The result is always non-negative.
+pub type EuclidRemainder<T, U> = <T as DivEuclid<U>>::Remainder;
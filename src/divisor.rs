@@ -0,0 +1,150 @@
+use crate::cmp::OrderingPredicates;
+use crate::Compare;
+use crate::Cons;
+use crate::Difference;
+use crate::Exp;
+use crate::Exponent;
+use crate::Factorize;
+use crate::False;
+use crate::GreatestCommonDivisor;
+use crate::IsEqual;
+use crate::IsZero;
+use crate::Next;
+use crate::Nil;
+use crate::One;
+use crate::Ordering;
+use crate::PrimeFactors;
+use crate::Product;
+use crate::Remainder;
+use crate::Simplified;
+use crate::Simplify;
+use crate::True;
+use crate::Zero;
+
+use core::ops::Mul;
+use core::ops::Rem;
+use core::ops::Sub;
+
+pub trait DividesStep<D, T> {
+    type Result;
+}
+
+impl<D, T> DividesStep<D, T> for True
+where
+    T: Compare<Zero>,
+    Ordering<T, Zero>: OrderingPredicates,
+{
+    type Result = IsZero<T>;
+}
+
+impl<D, T> DividesStep<D, T> for False
+where
+    T: Rem<D>,
+    Remainder<T, D>: Compare<Zero>,
+    Ordering<Remainder<T, D>, Zero>: OrderingPredicates,
+{
+    type Result = IsZero<Remainder<T, D>>;
+}
+
+/// Type-level divisibility test.
+pub trait DivisorOf<T> {
+    /// Whether `Self` divides `T`.
+    type Result;
+}
+
+impl<D, T> DivisorOf<T> for D
+where
+    D: Simplify,
+    T: Simplify,
+    Simplified<D>: Compare<Zero>,
+    Ordering<Simplified<D>, Zero>: OrderingPredicates,
+    IsZero<Simplified<D>>: DividesStep<Simplified<D>, Simplified<T>>,
+{
+    type Result = <IsZero<Simplified<D>> as DividesStep<Simplified<D>, Simplified<T>>>::Result;
+}
+
+/// Whether `T` divides `U`.
+///
+/// Zero only divides zero.
+pub type Divides<T, U> = <T as DivisorOf<U>>::Result;
+
+/// Type-level numbers that are divisible by `T`.
+pub trait Divisible<T> {}
+
+impl<T, U> Divisible<U> for T where U: DivisorOf<T, Result = True> {}
+
+/// Whether `T` and `U` are coprime.
+pub type Coprime<T, U> = IsEqual<GreatestCommonDivisor<T, U>, One>;
+
+pub trait FactorDivisorCount {
+    type Result;
+}
+
+impl FactorDivisorCount for Nil {
+    type Result = One;
+}
+
+impl<P, M, Tail> FactorDivisorCount for Cons<(P, M), Tail>
+where
+    Tail: FactorDivisorCount,
+    Next<M>: Mul<<Tail as FactorDivisorCount>::Result>,
+{
+    type Result = Product<Next<M>, <Tail as FactorDivisorCount>::Result>;
+}
+
+/// Type-level divisor count.
+pub trait CountDivisors {
+    /// The number of positive divisors of `Self`.
+    type Result;
+}
+
+impl<T> CountDivisors for T
+where
+    T: PrimeFactors,
+    Factorize<T>: FactorDivisorCount,
+{
+    type Result = <Factorize<T> as FactorDivisorCount>::Result;
+}
+
+/// The number of positive divisors of `T`.
+///
+/// Only implemented for positive integers.
+pub type NumDivisors<T> = <T as CountDivisors>::Result;
+
+pub trait FactorTotient {
+    type Result;
+}
+
+impl FactorTotient for Nil {
+    type Result = One;
+}
+
+impl<P, M, Tail> FactorTotient for Cons<(P, Next<M>), Tail>
+where
+    P: Exp<M> + Sub<One>,
+    Exponent<P, M>: Mul<Difference<P, One>>,
+    Tail: FactorTotient,
+    Product<Exponent<P, M>, Difference<P, One>>: Mul<<Tail as FactorTotient>::Result>,
+{
+    type Result =
+        Product<Product<Exponent<P, M>, Difference<P, One>>, <Tail as FactorTotient>::Result>;
+}
+
+/// Type-level Euler's totient function.
+pub trait EulerTotient {
+    /// The number of integers in `[1, Self]` that are coprime to `Self`.
+    type Result;
+}
+
+impl<T> EulerTotient for T
+where
+    T: PrimeFactors,
+    Factorize<T>: FactorTotient,
+{
+    type Result = <Factorize<T> as FactorTotient>::Result;
+}
+
+/// The number of integers in `[1, T]` that are coprime to `T`.
+///
+/// Only implemented for positive integers.
+pub type Totient<T> = <T as EulerTotient>::Result;
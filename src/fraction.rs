@@ -1,6 +1,7 @@
 use crate::rpn;
 use crate::Abs;
 use crate::Absolute;
+use crate::Compare;
 use crate::Exp;
 use crate::Exponent;
 use crate::Gcd;
@@ -13,11 +14,14 @@ use crate::NonNegative;
 use crate::NonPositive;
 use crate::NonZero;
 use crate::One;
+use crate::Ordering;
 use crate::Positive;
 use crate::Prev;
 use crate::Product;
 use crate::Quotient;
 use crate::Reify;
+use crate::Sign;
+use crate::Signum;
 use crate::Simplified;
 use crate::Simplify;
 use crate::Sum;
@@ -34,36 +38,6 @@ use core::ops::Sub;
 
 use local_type_alias::local_alias;
 
-pub trait Sign {
-    type Result;
-}
-
-impl Sign for Next<Zero> {
-    type Result = Next<Zero>;
-}
-
-impl<T> Sign for Next<Next<T>>
-where
-    Self: Positive,
-    Next<T>: Sign,
-{
-    type Result = <Next<T> as Sign>::Result;
-}
-
-impl Sign for Prev<Zero> {
-    type Result = Prev<Zero>;
-}
-
-impl<T> Sign for Prev<Prev<T>>
-where
-    Self: Negative,
-    Prev<T>: Sign,
-{
-    type Result = <Prev<T> as Sign>::Result;
-}
-
-type SignOf<T> = <T as Sign>::Result;
-
 /// A ratio of two numbers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Fraction<Num, Dem: Positive>(PhantomData<(Num, Dem)>);
@@ -110,22 +84,30 @@ where
     Dem: Simplify + Positive,
     Simplified<Num>: Abs + Sign,
     Simplified<Dem>: Abs + Sign,
-    SignOf<Simplified<Num>>: Mul<SignOf<Simplified<Dem>>>,
+    Signum<Simplified<Num>>: Mul<Signum<Simplified<Dem>>>,
     Absolute<Simplified<Num>>: Gcd<Absolute<Simplified<Dem>>> + Div<SimplifiedGcd>,
     Absolute<Simplified<Dem>>: Div<SimplifiedGcd>,
     rpn!(Dem simplify abs ({{SimplifiedGcd}}) /): Positive,
     rpn!(Num simplify abs ({{SimplifiedGcd}}) /):
-        Mul<rpn!(Num simplify [1 SignOf] Dem simplify [1 SignOf] *)>,
+        Mul<rpn!(Num simplify [1 Signum] Dem simplify [1 Signum] *)>,
 {
     type Result = rpn!(
                 Num simplify abs ({{SimplifiedGcd}}) /
-                Num simplify [1 SignOf] Dem simplify [1 SignOf] *
+                Num simplify [1 Signum] Dem simplify [1 Signum] *
             *
             Dem simplify abs ({{SimplifiedGcd}}) /
         fract
     );
 }
 
+impl<Num, Dem> Sign for Fraction<Num, Dem>
+where
+    Num: Sign,
+    Dem: Positive,
+{
+    type Result = Signum<Num>;
+}
+
 #[allow(unused_parens)]
 impl<Num1, Dem1, Num2, Dem2> Add<Fraction<Num2, Dem2>> for Fraction<Num1, Dem1>
 where
@@ -286,6 +268,42 @@ impl<Num, Dem: Positive> Mul<Fraction<Num, Dem>> for Zero {
     }
 }
 
+macro_rules! impl_mul_int {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl<Num, Dem, T> Mul<$name<T>> for Fraction<Num, Dem>
+            where
+                Dem: Positive,
+                Fraction<Num, Dem>: Mul<Fraction<$name<T>, One>>,
+                Product<Fraction<Num, Dem>, Fraction<$name<T>, One>>: Default,
+            {
+                type Output = Product<Fraction<Num, Dem>, Fraction<$name<T>, One>>;
+
+                #[inline(always)]
+                fn mul(self, _: $name<T>) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+
+            impl<Num, Dem, T> Mul<Fraction<Num, Dem>> for $name<T>
+            where
+                Dem: Positive,
+                Fraction<$name<T>, One>: Mul<Fraction<Num, Dem>>,
+                Product<Fraction<$name<T>, One>, Fraction<Num, Dem>>: Default,
+            {
+                type Output = Product<Fraction<$name<T>, One>, Fraction<Num, Dem>>;
+
+                #[inline(always)]
+                fn mul(self, _: Fraction<Num, Dem>) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+        )*
+    };
+}
+
+impl_mul_int![Next, Prev];
+
 impl<Num, Dem: Positive> Div<Fraction<Num, Dem>> for Zero {
     type Output = Zero;
 
@@ -304,6 +322,43 @@ impl<Num: Neg, Dem: Positive> Neg for Fraction<Num, Dem> {
     }
 }
 
+impl<Num1, Dem1, Num2, Dem2> Compare<Fraction<Num2, Dem2>> for Fraction<Num1, Dem1>
+where
+    Dem1: Positive,
+    Dem2: Positive,
+    Num1: Mul<Dem2>,
+    Num2: Mul<Dem1>,
+    Product<Num1, Dem2>: Compare<Product<Num2, Dem1>>,
+{
+    type Result = Ordering<Product<Num1, Dem2>, Product<Num2, Dem1>>;
+}
+
+macro_rules! impl_compare_int {
+    ($(<$($param:ident),*> $int:ty),* $(,)?) => {
+        $(
+            impl<Num, Dem, $($param),*> Compare<$int> for Fraction<Num, Dem>
+            where
+                Dem: Positive,
+                $int: Mul<Dem>,
+                Num: Compare<Product<$int, Dem>>,
+            {
+                type Result = Ordering<Num, Product<$int, Dem>>;
+            }
+
+            impl<Num, Dem, $($param),*> Compare<Fraction<Num, Dem>> for $int
+            where
+                Dem: Positive,
+                $int: Mul<Dem>,
+                Product<$int, Dem>: Compare<Num>,
+            {
+                type Result = Ordering<Product<$int, Dem>, Num>;
+            }
+        )*
+    };
+}
+
+impl_compare_int![<> Zero, <T> Next<T>, <T> Prev<T>];
+
 impl<Num: NonZero, Dem: Positive> NonZero for Fraction<Num, Dem> {}
 
 impl<Num: NonNegative, Dem: Positive> NonNegative for Fraction<Num, Dem> {}
@@ -344,6 +399,9 @@ where
     type Result = Quotient<Num, Dem>;
 }
 
+/// `T` as an integer.
+pub type Integral<T> = <T as ToInt>::Result;
+
 /// Type-level inverse.
 pub trait Inverse {
     /// The reciprocal of `Self`.
@@ -370,10 +428,10 @@ impl<Num, Dem> Inverse for Fraction<Num, Dem>
 where
     Num: Simplify + NonZero,
     Simplified<Num>: Abs + Sign,
-    Dem: Mul<SignOf<Simplified<Num>>> + Positive,
+    Dem: Mul<Signum<Simplified<Num>>> + Positive,
     Absolute<Simplified<Num>>: Positive, // Trivial
 {
-    type Result = Fraction<rpn!(Dem Num simplify [1 SignOf] *), Absolute<Simplified<Num>>>;
+    type Result = Fraction<rpn!(Dem Num simplify [1 Signum] *), Absolute<Simplified<Num>>>;
 }
 
 /// The reciprocal of `T`.
@@ -1,8 +1,30 @@
+use crate::cmp::OrderingPredicates;
+use crate::Abs;
+use crate::Absolute;
+use crate::Compare;
+use crate::Conditional;
+use crate::Difference;
+use crate::If;
+use crate::IsNegative;
+use crate::Negation;
 use crate::Next;
+use crate::One;
+use crate::Ordering;
+use crate::Prev;
+use crate::Product;
+use crate::Quotient;
 use crate::Remainder;
+use crate::Simplified;
+use crate::Simplify;
 use crate::Zero;
 
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
 use core::ops::Rem;
+use core::ops::Sub;
+
+use local_type_alias::local_alias;
 
 pub trait Reduce<T> {
     type This;
@@ -39,9 +61,9 @@ pub trait GcdInner<T, U> {
 impl<T, U> GcdInner<Next<T>, Next<U>> for Zero
 where
     Next<U>: Rem<Next<T>>,
-    Next<T>: Gcd<Remainder<Next<U>, Next<T>>>,
+    Next<T>: NonNegativeGcd<Remainder<Next<U>, Next<T>>>,
 {
-    type Result = GreatestCommonDivisor<Next<T>, Remainder<Next<U>, Next<T>>>;
+    type Result = <Next<T> as NonNegativeGcd<Remainder<Next<U>, Next<T>>>>::Result;
 }
 
 impl<T, U, V> GcdInner<Next<T>, Next<U>> for Next<V>
@@ -51,25 +73,23 @@ where
     type Result = <Zero as GcdInner<Next<U>, Next<T>>>::Result;
 }
 
-/// Type-level greatest common divisor.
-pub trait Gcd<T> {
-    /// The greatest common divisor of `Self` and `T`.
+pub trait NonNegativeGcd<T> {
     type Result;
 }
 
-impl Gcd<Zero> for Zero {
+impl NonNegativeGcd<Zero> for Zero {
     type Result = Zero;
 }
 
-impl<T> Gcd<Next<T>> for Zero {
+impl<T> NonNegativeGcd<Next<T>> for Zero {
     type Result = Next<T>;
 }
 
-impl<T> Gcd<Zero> for Next<T> {
+impl<T> NonNegativeGcd<Zero> for Next<T> {
     type Result = Next<T>;
 }
 
-impl<T, U> Gcd<Next<U>> for Next<T>
+impl<T, U> NonNegativeGcd<Next<U>> for Next<T>
 where
     Next<T>: Reduce<Next<U>>,
     <Next<T> as Reduce<Next<U>>>::This: GcdInner<Next<T>, Next<U>>,
@@ -77,5 +97,119 @@ where
     type Result = <<Next<T> as Reduce<Next<U>>>::This as GcdInner<Next<T>, Next<U>>>::Result;
 }
 
+/// Type-level greatest common divisor.
+///
+/// The result is always non-negative.
+pub trait Gcd<T> {
+    /// The greatest common divisor of `Self` and `T`.
+    type Result;
+}
+
+macro_rules! impl_gcd {
+    ($(<$($param:ident),*> $lhs:ty, $rhs:ty);* $(;)?) => {
+        $(
+            impl<$($param),*> Gcd<$rhs> for $lhs
+            where
+                $lhs: Simplify,
+                $rhs: Simplify,
+                Simplified<$lhs>: Abs,
+                Simplified<$rhs>: Abs,
+                Absolute<Simplified<$lhs>>: NonNegativeGcd<Absolute<Simplified<$rhs>>>,
+            {
+                type Result =
+                    <Absolute<Simplified<$lhs>> as NonNegativeGcd<Absolute<Simplified<$rhs>>>>::Result;
+            }
+        )*
+    };
+}
+
+impl_gcd![
+    <> Zero, Zero;
+    <U> Zero, Next<U>;
+    <U> Zero, Prev<U>;
+    <T> Next<T>, Zero;
+    <T> Prev<T>, Zero;
+    <T, U> Next<T>, Next<U>;
+    <T, U> Next<T>, Prev<U>;
+    <T, U> Prev<T>, Next<U>;
+    <T, U> Prev<T>, Prev<U>;
+];
+
 /// The greatest common divisor of `T` and `U`.
 pub type GreatestCommonDivisor<T, U> = <T as Gcd<U>>::Result;
+
+pub trait NonNegativeExtendedGcd<T> {
+    type Gcd;
+    type X;
+    type Y;
+}
+
+impl<T> NonNegativeExtendedGcd<Zero> for T {
+    type Gcd = T;
+    type X = One;
+    type Y = Zero;
+}
+
+#[local_alias]
+#[alias(
+    type Q = Quotient<T, Next<U>>,
+    type InnerGcd = <Next<U> as NonNegativeExtendedGcd<Remainder<T, Next<U>>>>::Gcd,
+    type InnerX = <Next<U> as NonNegativeExtendedGcd<Remainder<T, Next<U>>>>::X,
+    type InnerY = <Next<U> as NonNegativeExtendedGcd<Remainder<T, Next<U>>>>::Y,
+)]
+impl<T, U> NonNegativeExtendedGcd<Next<U>> for T
+where
+    T: Div<Next<U>> + Rem<Next<U>>,
+    Next<U>: NonNegativeExtendedGcd<Remainder<T, Next<U>>>,
+    Q: Mul<InnerY>,
+    InnerX: Sub<Product<Q, InnerY>>,
+{
+    type Gcd = InnerGcd;
+    type X = InnerY;
+    type Y = Difference<InnerX, Product<Q, InnerY>>;
+}
+
+/// Type-level extended Euclidean algorithm.
+///
+/// The coefficients satisfy `Self * X + T * Y = Gcd`.
+pub trait ExtendedGcd<T> {
+    /// The greatest common divisor of `Self` and `T`.
+    type Gcd;
+
+    /// The Bézout coefficient of `Self`.
+    type X;
+
+    /// The Bézout coefficient of `T`.
+    type Y;
+}
+
+#[local_alias]
+#[alias(
+    type InnerGcd = <Absolute<Simplified<T>> as NonNegativeExtendedGcd<Absolute<Simplified<U>>>>::Gcd,
+    type InnerX = <Absolute<Simplified<T>> as NonNegativeExtendedGcd<Absolute<Simplified<U>>>>::X,
+    type InnerY = <Absolute<Simplified<T>> as NonNegativeExtendedGcd<Absolute<Simplified<U>>>>::Y,
+)]
+impl<T, U> ExtendedGcd<U> for T
+where
+    T: Simplify + Compare<Zero>,
+    U: Simplify + Compare<Zero>,
+    Simplified<T>: Abs,
+    Simplified<U>: Abs,
+    Absolute<Simplified<T>>: NonNegativeExtendedGcd<Absolute<Simplified<U>>>,
+    InnerX: Neg,
+    InnerY: Neg,
+    Ordering<T, Zero>: OrderingPredicates,
+    Ordering<U, Zero>: OrderingPredicates,
+    IsNegative<T>: Conditional<Negation<InnerX>, InnerX>,
+    IsNegative<U>: Conditional<Negation<InnerY>, InnerY>,
+{
+    type Gcd = InnerGcd;
+    type X = If<IsNegative<T>, Negation<InnerX>, InnerX>;
+    type Y = If<IsNegative<U>, Negation<InnerY>, InnerY>;
+}
+
+/// The Bézout coefficient of `T` in the extended Euclidean algorithm on `T` and `U`.
+pub type BezoutX<T, U> = <T as ExtendedGcd<U>>::X;
+
+/// The Bézout coefficient of `U` in the extended Euclidean algorithm on `T` and `U`.
+pub type BezoutY<T, U> = <T as ExtendedGcd<U>>::Y;
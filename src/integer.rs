@@ -0,0 +1,91 @@
+use crate::Next;
+use crate::Ones;
+use crate::Prev;
+use crate::Zero;
+use crate::B0;
+use crate::B1;
+
+macro_rules! integer_consts {
+    ($($name:ident: $ty:ident $(, $unsigned:ident)?;)*) => {
+        $(
+            #[doc = concat!("The value of `Self` as `", stringify!($ty), "`.")]
+            #[allow(
+                clippy::cast_lossless,
+                clippy::cast_possible_truncation,
+                clippy::cast_possible_wrap,
+                clippy::cast_sign_loss
+            )]
+            const $name: $ty = {
+                let value = Self::I128 as $ty;
+                assert!(
+                    integer_consts!(@non_negative $($unsigned)?) && value as i128 == Self::I128,
+                    concat!("value out of range for `", stringify!($ty), "`"),
+                );
+                value
+            };
+        )*
+    };
+    (@non_negative) => { true };
+    (@non_negative unsigned) => { Self::I128 >= 0 };
+}
+
+/// Type-level integers whose value is available as associated constants.
+///
+/// Unlike [`Reify`](crate::Reify), these constants can be used without naming
+/// the runtime type, e.g. as `[u8; <N as Integer>::USIZE]`. Using a constant
+/// whose type cannot represent the value of `Self` fails to compile.
+///
+/// ```
+/// use peano_axioms::{Four, Integer, Product, Three};
+///
+/// let buffer = [0u8; <Product<Three, Four> as Integer>::USIZE];
+/// assert_eq!(buffer.len(), 12);
+/// ```
+///
+/// ```compile_fail
+/// use peano_axioms::{Integer, Negation, One};
+///
+/// const MINUS_ONE: u8 = <Negation<One> as Integer>::U8;
+/// ```
+pub trait Integer {
+    /// The value of `Self` as `i128`.
+    const I128: i128;
+
+    integer_consts! {
+        I8: i8;
+        I16: i16;
+        I32: i32;
+        I64: i64;
+        ISIZE: isize;
+        U8: u8, unsigned;
+        U16: u16, unsigned;
+        U32: u32, unsigned;
+        U64: u64, unsigned;
+        U128: u128, unsigned;
+        USIZE: usize, unsigned;
+    }
+}
+
+impl Integer for Zero {
+    const I128: i128 = 0;
+}
+
+impl Integer for Ones {
+    const I128: i128 = -1;
+}
+
+impl<T: Integer> Integer for Next<T> {
+    const I128: i128 = T::I128 + 1;
+}
+
+impl<T: Integer> Integer for Prev<T> {
+    const I128: i128 = T::I128 - 1;
+}
+
+impl<T: Integer> Integer for B0<T> {
+    const I128: i128 = T::I128 * 2;
+}
+
+impl<T: Integer> Integer for B1<T> {
+    const I128: i128 = T::I128 * 2 + 1;
+}
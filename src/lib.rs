@@ -4,20 +4,174 @@
 #![recursion_limit = "256"]
 #![cfg_attr(test, allow(unused_parens))]
 
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use peano_axioms_macros as __macros;
+
+mod binary;
+pub use binary::IntoBinary;
+pub use binary::IntoUnary;
+pub use binary::Ones;
+pub use binary::ToBinary;
+pub use binary::ToUnary;
+pub use binary::B0;
+pub use binary::B1;
+
+mod bits;
+pub use bits::BitwiseAnd;
+pub use bits::BitwiseOr;
+pub use bits::BitwiseXor;
+pub use bits::LeftShift;
+pub use bits::RightShift;
+
+mod cmp;
+pub use cmp::Clamp;
+pub use cmp::Clamped;
+pub use cmp::Compare;
+pub use cmp::Equal;
+pub use cmp::Greater;
+pub use cmp::IsEqual;
+pub use cmp::IsGreater;
+pub use cmp::IsGreaterOrEqual;
+pub use cmp::IsLess;
+pub use cmp::IsLessOrEqual;
+pub use cmp::IsNegative;
+pub use cmp::IsNonNegative;
+pub use cmp::IsNonPositive;
+pub use cmp::IsNonZero;
+pub use cmp::IsNotEqual;
+pub use cmp::IsPositive;
+pub use cmp::IsZero;
+pub use cmp::Less;
+pub use cmp::Max;
+pub use cmp::Maximum;
+pub use cmp::Min;
+pub use cmp::Minimum;
+pub use cmp::Ordering;
+
+mod combinatorics;
+pub use combinatorics::Binomial;
+pub use combinatorics::Choose;
+pub use combinatorics::Fact;
+pub use combinatorics::Factorial;
+pub use combinatorics::Permutations;
+pub use combinatorics::Permute;
+
 mod div;
+pub use div::CeilQuotient;
+pub use div::DivCeil;
+pub use div::DivEuclid;
+pub use div::DivFloor;
+pub use div::DivRound;
+pub use div::EuclidQuotient;
+pub use div::EuclidRemainder;
+pub use div::FloorQuotient;
 pub use div::Quotient;
 pub use div::Remainder;
+pub use div::RoundQuotient;
+
+mod constant;
+pub use constant::ConstInt;
+pub use constant::ConstToPeano;
+pub use constant::ConstUsize;
+pub use constant::Int;
+pub use constant::Usize;
+
+mod digits;
+pub use digits::Decompose;
+pub use digits::DigitSum;
+pub use digits::Digits;
+pub use digits::NumDigits;
+pub use digits::SumDigits;
+
+mod divisor;
+pub use divisor::Coprime;
+pub use divisor::CountDivisors;
+pub use divisor::Divides;
+pub use divisor::Divisible;
+pub use divisor::DivisorOf;
+pub use divisor::EulerTotient;
+pub use divisor::NumDivisors;
+pub use divisor::Totient;
 
 mod fraction;
 pub use fraction::Fraction;
+pub use fraction::Integral;
 pub use fraction::Inverse;
 pub use fraction::Reciprocal;
 pub use fraction::ToInt;
 
 mod gcd;
+pub use gcd::BezoutX;
+pub use gcd::BezoutY;
+pub use gcd::ExtendedGcd;
 pub use gcd::Gcd;
 pub use gcd::GreatestCommonDivisor;
 
+mod integer;
+pub use integer::Integer;
+
+mod list;
+pub use list::Cons;
+pub use list::Len;
+pub use list::Length;
+pub use list::Nil;
+
+mod log;
+pub use log::CeilLog2;
+pub use log::CeilLogBase;
+pub use log::IsPowerOf;
+pub use log::Log;
+pub use log::Log2;
+pub use log::LogBase;
+
+mod logic;
+pub use logic::And;
+pub use logic::Boolean;
+pub use logic::Complement;
+pub use logic::Conditional;
+pub use logic::Conjunction;
+pub use logic::Disjunction;
+pub use logic::ExclusiveDisjunction;
+pub use logic::False;
+pub use logic::If;
+pub use logic::Not;
+pub use logic::Or;
+pub use logic::True;
+pub use logic::Xor;
+
+mod modular;
+pub use modular::ModInverse;
+pub use modular::ModPow;
+pub use modular::ModularExp;
+pub use modular::ModularInverse;
+
+mod parity;
+pub use parity::Even;
+pub use parity::IsEven;
+pub use parity::IsOdd;
+pub use parity::IsPowerOfTwo;
+pub use parity::Odd;
+pub use parity::Parity;
+pub use parity::PowerOfTwo;
+pub use parity::PowerOfTwoTest;
+
+mod prime;
+pub use prime::Factorize;
+pub use prime::IsPrime;
+pub use prime::Primality;
+pub use prime::Prime;
+pub use prime::PrimeFactors;
+
+mod root;
+pub use root::ExactSqrt;
+pub use root::ExactSquareRoot;
+pub use root::IsPerfectSquare;
+pub use root::NthRoot;
+pub use root::Root;
+pub use root::Sqrt;
+pub use root::SquareRoot;
+
 use core::fmt;
 use core::hash;
 use core::marker::PhantomData;
@@ -736,6 +890,37 @@ where
 /// The absolute value of `T`.
 pub type Absolute<T> = <T as Abs>::Result;
 
+/// Type-level sign.
+pub trait Sign {
+    /// The sign of `Self`; one of `-1`, [`Zero`] or [`One`].
+    ///
+    /// For binary numbers, these are [`Ones`], [`Zero`] and `B1<Zero>`.
+    type Result;
+}
+
+impl Sign for Zero {
+    type Result = Zero;
+}
+
+impl<T> Sign for Next<T>
+where
+    Self: Compare<Zero>,
+    Ordering<Self, Zero>: cmp::OrderingSign,
+{
+    type Result = <Ordering<Self, Zero> as cmp::OrderingSign>::Result;
+}
+
+impl<T> Sign for Prev<T>
+where
+    Self: Compare<Zero>,
+    Ordering<Self, Zero>: cmp::OrderingSign,
+{
+    type Result = <Ordering<Self, Zero> as cmp::OrderingSign>::Result;
+}
+
+/// The sign of `T`: `-1` if `T` is negative, [`Zero`] if it is zero and [`One`] if it is positive.
+pub type Signum<T> = <T as Sign>::Result;
+
 /// Simplification of redundancies in type-level numbers.
 pub trait Simplify {
     /// The simplification of `Self`.
@@ -883,7 +1068,7 @@ macro_rules! rpn_impl {
    (((simplify     ) $($rest:tt)*) ($a:tt       $($stack:tt)*)) => { $crate::rpn_impl!(($($rest)*) (($crate::Simplified<$a>               ) $($stack)*)) };
    (((fract        ) $($rest:tt)*) ($a:tt $b:tt $($stack:tt)*)) => { $crate::rpn_impl!(($($rest)*) (($crate::Fraction<$b, $a>             ) $($stack)*)) };
    (((inv          ) $($rest:tt)*) ($a:tt       $($stack:tt)*)) => { $crate::rpn_impl!(($($rest)*) (($crate::Reciprocal<$a>               ) $($stack)*)) };
-   (((int          ) $($rest:tt)*) ($a:tt       $($stack:tt)*)) => { $crate::rpn_impl!(($($rest)*) (($crate::ToInt<$a>                    ) $($stack)*)) };
+   (((int          ) $($rest:tt)*) ($a:tt       $($stack:tt)*)) => { $crate::rpn_impl!(($($rest)*) (($crate::Integral<$a>                 ) $($stack)*)) };
    (((dup          ) $($rest:tt)*) ($a:tt       $($stack:tt)*)) => { $crate::rpn_impl!(($($rest)*) (($a               ) ($a               ) $($stack)*)) };
 
    // Custom operators
@@ -891,6 +1076,7 @@ macro_rules! rpn_impl {
    ((([2 $op:ident]) $($rest:tt)*) ($a:tt $b:tt $($stack:tt)*)) => { $crate::rpn_impl!(($($rest)*) (($op<$b, $a>                          ) $($stack)*)) };
 
    // Operands
+   (((($val:ty)    ) $($rest:tt)*) (            $($stack:tt)*)) => { $crate::rpn_impl!(($($rest)*) ($val                                    $($stack)*)) };
    ((($val:ty      ) $($rest:tt)*) (            $($stack:tt)*)) => { $crate::rpn_impl!(($($rest)*) ($val                                    $($stack)*)) };
 
    // Done
@@ -917,5 +1103,56 @@ macro_rules! rpn {
     ($($t:tt)+) => { $crate::rpn_impl!(($(($t))+) ()) };
 }
 
+/// Infix notation for type-level numerical expressions.
+///
+/// Expands to the same type as the equivalent [`rpn!`] expression, so
+/// `expr!(3 * (A + 4) / B)` is `rpn!(3 (A) 4 + * (B) /)`.
+///
+/// The binary operators are `+`, `-`, `*`, `/`, `%` and `^`, with the usual
+/// precedence: `^` binds tightest and is right-associative, followed by unary
+/// `-` (so `-2 ^ 2` is `-4`), then `*`, `/` and `%`, then `+` and `-`.
+///
+/// The available functions are `abs(x)`, `gcd(x, y)`, `lcm(x, y)`,
+/// `simplify(x)`, `fract(x, y)`, `inv(x)` and `int(x)`, which correspond to the
+/// [`rpn!`] operators of the same name.
+///
+/// Integer literals are expanded with [`peano!`] if they are too large to be
+/// [`rpn!`] numerals. Operands can also be type paths such as `T`, `Next<T>` or
+/// `<T as Trait>::Result`, or any other type in parentheses.
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! expr {
+    ($($t:tt)+) => { $crate::__macros::expr!(($crate) $($t)+) };
+}
+
+/// Expands an integer literal into the canonical unary type-level number.
+///
+/// Positive numbers become a chain of [`Next`], negative numbers a chain of
+/// [`Prev`], so `peano!(3)` is `Next<Next<Next<Zero>>>` and `peano!(-1)` is
+/// `Prev<Zero>`. Binary, octal and hexadecimal literals, digit separators and
+/// integer suffixes are accepted.
+///
+/// Any magnitude up to `u16::MAX` is expanded, but trait resolution on unary
+/// numbers recurses once per `Next` or `Prev`, so numbers larger than about
+/// 100 need a higher `#![recursion_limit]` in the calling crate. Prefer
+/// [`peano_binary!`] for large numbers.
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! peano {
+    ($($t:tt)+) => { $crate::__macros::peano!(($crate) $($t)+) };
+}
+
+/// Expands an integer literal into the canonical binary type-level number.
+///
+/// The result is built from [`B0`], [`B1`], [`Zero`] and [`Ones`] in two's
+/// complement, least significant bit first, so `peano_binary!(6)` is
+/// `B0<B1<B1<Zero>>>` and `peano_binary!(-3)` is `B1<B0<Ones>>`. Any literal
+/// accepted by [`peano!`] is accepted, as long as it fits in an `i128`.
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! peano_binary {
+    ($($t:tt)+) => { $crate::__macros::peano_binary!(($crate) $($t)+) };
+}
+
 #[cfg(test)]
 mod test;
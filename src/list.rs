@@ -0,0 +1,42 @@
+use crate::Next;
+use crate::Zero;
+
+use core::marker::PhantomData;
+
+/// The empty type-level list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Nil;
+
+impl Nil {
+    /// The value associated with this type.
+    pub const VALUE: Nil = Nil;
+}
+
+/// A type-level list consisting of `Head` followed by the list `Tail`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Cons<Head, Tail>(PhantomData<(Head, Tail)>);
+
+impl<Head, Tail> Cons<Head, Tail> {
+    /// The value associated with this type.
+    pub const VALUE: Self = Cons(PhantomData);
+}
+
+/// Type-level list length.
+pub trait Len {
+    /// The number of elements in `Self`.
+    type Result;
+}
+
+impl Len for Nil {
+    type Result = Zero;
+}
+
+impl<Head, Tail> Len for Cons<Head, Tail>
+where
+    Tail: Len,
+{
+    type Result = Next<<Tail as Len>::Result>;
+}
+
+/// The number of elements in the list `T`.
+pub type Length<T> = <T as Len>::Result;
@@ -0,0 +1,115 @@
+use crate::cmp::OrderingPredicates;
+use crate::Compare;
+use crate::Conditional;
+use crate::Exp;
+use crate::Exponent;
+use crate::False;
+use crate::Greater;
+use crate::If;
+use crate::Integer;
+use crate::IsEqual;
+use crate::IsLess;
+use crate::Next;
+use crate::One;
+use crate::Ordering;
+use crate::Positive;
+use crate::Quotient;
+use crate::Simplified;
+use crate::Simplify;
+use crate::True;
+use crate::Two;
+use crate::Zero;
+
+use core::ops::Div;
+
+use local_type_alias::local_alias;
+
+pub trait FloorLog<B> {
+    type Result;
+}
+
+impl<T, B> FloorLog<B> for T
+where
+    T: Compare<B>,
+    Ordering<T, B>: OrderingPredicates,
+    IsLess<T, B>: LogStep<T, B>,
+{
+    type Result = <IsLess<T, B> as LogStep<T, B>>::Result;
+}
+
+pub trait LogStep<T, B> {
+    type Result;
+}
+
+impl<T, B> LogStep<T, B> for True {
+    type Result = Zero;
+}
+
+impl<T, B> LogStep<T, B> for False
+where
+    T: Div<B>,
+    Quotient<T, B>: FloorLog<B>,
+{
+    type Result = Next<<Quotient<T, B> as FloorLog<B>>::Result>;
+}
+
+/// Type-level integer logarithm.
+pub trait Log<B> {
+    /// The base `B` logarithm of `Self`, rounded down.
+    type Floor;
+
+    /// The base `B` logarithm of `Self`, rounded up.
+    type Ceil;
+}
+
+#[local_alias]
+#[alias(
+    type N = Simplified<T>,
+    type C = Simplified<B>,
+    type Rounded = <N as FloorLog<C>>::Result,
+    type Exact = IsEqual<Exponent<C, Rounded>, N>,
+)]
+impl<T, B> Log<B> for T
+where
+    T: Simplify,
+    B: Simplify,
+    N: Integer + Positive + FloorLog<C>,
+    C: Integer + Compare<One, Result = Greater> + Exp<Rounded>,
+    Exponent<C, Rounded>: Compare<N>,
+    Ordering<Exponent<C, Rounded>, N>: OrderingPredicates,
+    Exact: Conditional<Rounded, Next<Rounded>>,
+{
+    type Floor = Rounded;
+
+    type Ceil = If<Exact, Rounded, Next<Rounded>>;
+}
+
+/// The base `B` logarithm of `T`, rounded down.
+///
+/// Only implemented for positive integers `T` and integers `B` greater than
+/// one. Fractions are rejected:
+///
+/// ```compile_fail
+/// use peano_axioms::{Fraction, LogBase, One, Four, Two};
+///
+/// let log = LogBase::<Fraction<One, Four>, Two>::default();
+/// ```
+pub type LogBase<T, B> = <T as Log<B>>::Floor;
+
+/// The base `B` logarithm of `T`, rounded up.
+///
+/// Only implemented for positive integers `T` and integers `B` greater than
+/// one.
+pub type CeilLogBase<T, B> = <T as Log<B>>::Ceil;
+
+/// The base 2 logarithm of `T`, rounded down.
+pub type Log2<T> = LogBase<T, Two>;
+
+/// The base 2 logarithm of `T`, rounded up.
+pub type CeilLog2<T> = CeilLogBase<T, Two>;
+
+/// Whether `T` is an integer power of `B`.
+///
+/// Only implemented for positive integers `T` and integers `B` greater than
+/// one.
+pub type IsPowerOf<T, B> = IsEqual<Exponent<Simplified<B>, LogBase<T, B>>, Simplified<T>>;
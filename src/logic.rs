@@ -0,0 +1,122 @@
+use crate::Reify;
+
+/// Type-level boolean values.
+pub trait Boolean: Reify<bool> {}
+
+/// The boolean value `true`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct True;
+
+impl True {
+    /// The value associated with this type.
+    pub const VALUE: True = True;
+}
+
+impl Reify<bool> for True {
+    const REIFIED: bool = true;
+}
+
+impl Boolean for True {}
+
+/// The boolean value `false`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct False;
+
+impl False {
+    /// The value associated with this type.
+    pub const VALUE: False = False;
+}
+
+impl Reify<bool> for False {
+    const REIFIED: bool = false;
+}
+
+impl Boolean for False {}
+
+/// Type-level logical negation.
+pub trait Not {
+    /// The negation of `Self`.
+    type Result;
+}
+
+impl Not for True {
+    type Result = False;
+}
+
+impl Not for False {
+    type Result = True;
+}
+
+/// The logical negation of `T`.
+pub type Complement<T> = <T as Not>::Result;
+
+/// Type-level logical conjunction.
+pub trait And<T> {
+    /// `true` if both `Self` and `T` are `true`.
+    type Result;
+}
+
+impl<T: Boolean> And<T> for True {
+    type Result = T;
+}
+
+impl<T: Boolean> And<T> for False {
+    type Result = False;
+}
+
+/// The logical conjunction of `T` and `U`.
+pub type Conjunction<T, U> = <T as And<U>>::Result;
+
+/// Type-level logical disjunction.
+pub trait Or<T> {
+    /// `true` if either `Self` or `T` is `true`.
+    type Result;
+}
+
+impl<T: Boolean> Or<T> for True {
+    type Result = True;
+}
+
+impl<T: Boolean> Or<T> for False {
+    type Result = T;
+}
+
+/// The logical disjunction of `T` and `U`.
+pub type Disjunction<T, U> = <T as Or<U>>::Result;
+
+/// Type-level exclusive disjunction.
+pub trait Xor<T> {
+    /// `true` if exactly one of `Self` and `T` is `true`.
+    type Result;
+}
+
+impl<T: Boolean + Not> Xor<T> for True {
+    type Result = Complement<T>;
+}
+
+impl<T: Boolean> Xor<T> for False {
+    type Result = T;
+}
+
+/// The exclusive disjunction of `T` and `U`.
+pub type ExclusiveDisjunction<T, U> = <T as Xor<U>>::Result;
+
+/// Type-level conditional selection.
+pub trait Conditional<Then, Else> {
+    /// `Then` if `Self` is `true`, otherwise `Else`.
+    type Result;
+}
+
+impl<Then, Else> Conditional<Then, Else> for True {
+    type Result = Then;
+}
+
+impl<Then, Else> Conditional<Then, Else> for False {
+    type Result = Else;
+}
+
+/// `Then` if `Cond` is `true`, otherwise `Else`.
+///
+/// Both branches are always resolved, so each of them must be a valid type
+/// regardless of `Cond`.
+pub type If<Cond, Then, Else> = <Cond as Conditional<Then, Else>>::Result;
@@ -0,0 +1,116 @@
+use crate::BezoutX;
+use crate::DivEuclid;
+use crate::EuclidRemainder;
+use crate::Exp;
+use crate::Exponent;
+use crate::ExtendedGcd;
+use crate::Negation;
+use crate::Negative;
+use crate::Next;
+use crate::One;
+use crate::Positive;
+use crate::Prev;
+use crate::Product;
+use crate::Quotient;
+use crate::Remainder;
+use crate::Simplified;
+use crate::Simplify;
+use crate::Two;
+use crate::Zero;
+
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Rem;
+
+use local_type_alias::local_alias;
+
+/// Type-level modular multiplicative inverse.
+pub trait ModularInverse<M> {
+    /// The inverse of `Self` modulo `M`.
+    type Result;
+}
+
+#[local_alias]
+#[alias(type N = Simplified<M>)]
+impl<T, M> ModularInverse<M> for T
+where
+    M: Simplify,
+    N: Positive,
+    T: ExtendedGcd<N, Gcd = One>,
+    BezoutX<T, N>: DivEuclid<N>,
+{
+    type Result = EuclidRemainder<BezoutX<T, N>, N>;
+}
+
+/// The inverse of `T` modulo `M`, in the range `[0, M)`.
+///
+/// Fails to compile if `T` and `M` are not coprime.
+pub type ModInverse<T, M> = <T as ModularInverse<M>>::Result;
+
+/// Type-level modular exponentiation.
+pub trait ModularExp<E, M> {
+    /// `Self` raised to the power of `E`, modulo `M`.
+    type Result;
+}
+
+impl<T, E, M> ModularExp<E, M> for T
+where
+    E: Simplify,
+    M: Simplify,
+    T: ExpBySquaring<Simplified<E>, Simplified<M>>,
+{
+    type Result = <T as ExpBySquaring<Simplified<E>, Simplified<M>>>::Result;
+}
+
+pub trait ExpBySquaring<E, M> {
+    type Result;
+}
+
+impl<T, M> ExpBySquaring<Zero, M> for T
+where
+    M: Positive,
+    // Not needed for the result, but rejects bases that are not integers, as
+    // the other exponents do.
+    T: DivEuclid<M>,
+    One: Rem<M>,
+{
+    type Result = Remainder<One, M>;
+}
+
+#[local_alias]
+#[alias(
+    type Half = <T as ExpBySquaring<Quotient<Next<E>, Two>, M>>::Result,
+    type HalfSquared = EuclidRemainder<Product<Half, Half>, M>,
+    type Extra = Exponent<EuclidRemainder<T, M>, Remainder<Next<E>, Two>>,
+)]
+impl<T, E, M> ExpBySquaring<Next<E>, M> for T
+where
+    M: Positive,
+    Next<E>: Positive + Div<Two> + Rem<Two>,
+    T: ExpBySquaring<Quotient<Next<E>, Two>, M> + DivEuclid<M>,
+    Half: Mul<Half>,
+    Product<Half, Half>: DivEuclid<M>,
+    EuclidRemainder<T, M>: Exp<Remainder<Next<E>, Two>>,
+    HalfSquared: Mul<Extra>,
+    Product<HalfSquared, Extra>: DivEuclid<M>,
+{
+    type Result = EuclidRemainder<Product<HalfSquared, Extra>, M>;
+}
+
+impl<T, E, M> ExpBySquaring<Prev<E>, M> for T
+where
+    M: Positive,
+    Prev<E>: Neg + Negative,
+    T: ModularInverse<M>,
+    ModInverse<T, M>: ExpBySquaring<Negation<Prev<E>>, M>,
+{
+    type Result = <ModInverse<T, M> as ExpBySquaring<Negation<Prev<E>>, M>>::Result;
+}
+
+/// `T` raised to the power of `E`, modulo `M`, in the range `[0, M)`.
+///
+/// The intermediate results are reduced modulo `M` at each step, so large
+/// exponents do not produce large intermediate types. Negative exponents
+/// require `T` and `M` to be coprime.
+pub type ModPow<T, E, M> = <T as ModularExp<E, M>>::Result;
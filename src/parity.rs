@@ -0,0 +1,135 @@
+use crate::cmp::OrderingPredicates;
+use crate::Compare;
+use crate::Complement;
+use crate::False;
+use crate::IsEqual;
+use crate::IsPositive;
+use crate::IsZero;
+use crate::Not;
+use crate::One;
+use crate::Ordering;
+use crate::Quotient;
+use crate::Remainder;
+use crate::Simplified;
+use crate::Simplify;
+use crate::True;
+use crate::Two;
+use crate::Zero;
+
+use core::ops::Div;
+use core::ops::Rem;
+
+/// Type-level parity.
+pub trait Parity {
+    /// Whether `Self` is even.
+    type IsEven;
+
+    /// Whether `Self` is odd.
+    type IsOdd;
+}
+
+impl<T> Parity for T
+where
+    T: Simplify,
+    Simplified<T>: Rem<Two>,
+    Remainder<Simplified<T>, Two>: Compare<Zero>,
+    Ordering<Remainder<Simplified<T>, Two>, Zero>: OrderingPredicates,
+    IsZero<Remainder<Simplified<T>, Two>>: Not,
+{
+    type IsEven = IsZero<Remainder<Simplified<T>, Two>>;
+
+    type IsOdd = Complement<IsZero<Remainder<Simplified<T>, Two>>>;
+}
+
+/// Whether `T` is even.
+pub type IsEven<T> = <T as Parity>::IsEven;
+
+/// Whether `T` is odd.
+pub type IsOdd<T> = <T as Parity>::IsOdd;
+
+/// Even type-level numbers.
+pub trait Even {}
+
+impl<T> Even for T where T: Parity<IsEven = True> {}
+
+/// Odd type-level numbers.
+pub trait Odd {}
+
+impl<T> Odd for T where T: Parity<IsOdd = True> {}
+
+pub trait HalvingStep<T> {
+    type Result;
+}
+
+impl<T> HalvingStep<T> for True {
+    type Result = True;
+}
+
+impl<T> HalvingStep<T> for False
+where
+    T: Parity,
+    IsEven<T>: EvenStep<T>,
+{
+    type Result = <IsEven<T> as EvenStep<T>>::Result;
+}
+
+pub trait EvenStep<T> {
+    type Result;
+}
+
+impl<T> EvenStep<T> for False {
+    type Result = False;
+}
+
+impl<T> EvenStep<T> for True
+where
+    T: Div<Two>,
+    Quotient<T, Two>: Compare<One>,
+    Ordering<Quotient<T, Two>, One>: OrderingPredicates,
+    IsEqual<Quotient<T, Two>, One>: HalvingStep<Quotient<T, Two>>,
+{
+    type Result = <IsEqual<Quotient<T, Two>, One> as HalvingStep<Quotient<T, Two>>>::Result;
+}
+
+pub trait PowerOfTwoStep<T> {
+    type Result;
+}
+
+impl<T> PowerOfTwoStep<T> for False {
+    type Result = False;
+}
+
+impl<T> PowerOfTwoStep<T> for True
+where
+    T: Compare<One>,
+    Ordering<T, One>: OrderingPredicates,
+    IsEqual<T, One>: HalvingStep<T>,
+{
+    type Result = <IsEqual<T, One> as HalvingStep<T>>::Result;
+}
+
+/// Type-level power-of-two test.
+pub trait PowerOfTwoTest {
+    /// Whether `Self` is an integer power of two.
+    type Result;
+}
+
+impl<T> PowerOfTwoTest for T
+where
+    T: Simplify,
+    Simplified<T>: Compare<Zero>,
+    Ordering<Simplified<T>, Zero>: OrderingPredicates,
+    IsPositive<Simplified<T>>: PowerOfTwoStep<Simplified<T>>,
+{
+    type Result = <IsPositive<Simplified<T>> as PowerOfTwoStep<Simplified<T>>>::Result;
+}
+
+/// Whether `T` is an integer power of two.
+///
+/// This is `false` for zero and negative numbers.
+pub type IsPowerOfTwo<T> = <T as PowerOfTwoTest>::Result;
+
+/// Type-level numbers that are integer powers of two.
+pub trait PowerOfTwo {}
+
+impl<T> PowerOfTwo for T where T: PowerOfTwoTest<Result = True> {}
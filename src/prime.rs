@@ -0,0 +1,216 @@
+use crate::cmp::OrderingPredicates;
+use crate::Compare;
+use crate::Cons;
+use crate::False;
+use crate::IsEqual;
+use crate::IsGreater;
+use crate::IsZero;
+use crate::Next;
+use crate::Nil;
+use crate::One;
+use crate::Ordering;
+use crate::Positive;
+use crate::Product;
+use crate::Quotient;
+use crate::Remainder;
+use crate::Simplified;
+use crate::Simplify;
+use crate::True;
+use crate::Two;
+use crate::Zero;
+
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Rem;
+
+use local_type_alias::local_alias;
+
+pub trait TrialDivision<T> {
+    type Result;
+}
+
+// Candidate divisors are always positive, and implementing this for every type
+// makes rustdoc's blanket impl search blow up.
+impl<D, T> TrialDivision<T> for Next<D>
+where
+    Self: Mul<Self>,
+    Product<Self, Self>: Compare<T>,
+    Ordering<Product<Self, Self>, T>: OrderingPredicates,
+    IsGreater<Product<Self, Self>, T>: TrialStep<Self, T>,
+{
+    type Result = <IsGreater<Product<Self, Self>, T> as TrialStep<Self, T>>::Result;
+}
+
+pub trait TrialStep<D, T> {
+    type Result;
+}
+
+impl<D, T> TrialStep<D, T> for True {
+    type Result = T;
+}
+
+impl<D, T> TrialStep<D, T> for False
+where
+    T: Rem<D>,
+    Remainder<T, D>: Compare<Zero>,
+    Ordering<Remainder<T, D>, Zero>: OrderingPredicates,
+    IsZero<Remainder<T, D>>: DivisorStep<D, T>,
+{
+    type Result = <IsZero<Remainder<T, D>> as DivisorStep<D, T>>::Result;
+}
+
+pub trait DivisorStep<D, T> {
+    type Result;
+}
+
+impl<D, T> DivisorStep<D, T> for True {
+    type Result = D;
+}
+
+impl<D, T> DivisorStep<D, T> for False
+where
+    Next<D>: TrialDivision<T>,
+{
+    type Result = <Next<D> as TrialDivision<T>>::Result;
+}
+
+/// The smallest factor of `T` greater than one.
+type SmallestFactor<T> = <Two as TrialDivision<T>>::Result;
+
+pub trait PrimeStep<T> {
+    type Result;
+}
+
+impl<T> PrimeStep<T> for False {
+    type Result = False;
+}
+
+impl<T> PrimeStep<T> for True
+where
+    Two: TrialDivision<T>,
+    SmallestFactor<T>: Compare<T>,
+    Ordering<SmallestFactor<T>, T>: OrderingPredicates,
+{
+    type Result = IsEqual<SmallestFactor<T>, T>;
+}
+
+/// Type-level primality test.
+pub trait Primality {
+    /// Whether `Self` is prime.
+    type Result;
+}
+
+#[local_alias]
+#[alias(type N = Simplified<T>)]
+impl<T> Primality for T
+where
+    T: Simplify,
+    N: Compare<One>,
+    Ordering<N, One>: OrderingPredicates,
+    IsGreater<N, One>: PrimeStep<N>,
+{
+    type Result = <IsGreater<N, One> as PrimeStep<N>>::Result;
+}
+
+/// Whether `T` is prime.
+pub type IsPrime<T> = <T as Primality>::Result;
+
+/// Prime type-level numbers.
+pub trait Prime {}
+
+impl<T> Prime for T where T: Primality<Result = True> {}
+
+pub trait DivideOut<T> {
+    type Count;
+    type Rest;
+}
+
+impl<P, T> DivideOut<T> for P
+where
+    T: Rem<P>,
+    Remainder<T, P>: Compare<Zero>,
+    Ordering<Remainder<T, P>, Zero>: OrderingPredicates,
+    IsZero<Remainder<T, P>>: DivideOutStep<P, T>,
+{
+    type Count = <IsZero<Remainder<T, P>> as DivideOutStep<P, T>>::Count;
+    type Rest = <IsZero<Remainder<T, P>> as DivideOutStep<P, T>>::Rest;
+}
+
+pub trait DivideOutStep<P, T> {
+    type Count;
+    type Rest;
+}
+
+impl<P, T> DivideOutStep<P, T> for False {
+    type Count = Zero;
+    type Rest = T;
+}
+
+impl<P, T> DivideOutStep<P, T> for True
+where
+    T: Div<P>,
+    P: DivideOut<Quotient<T, P>>,
+{
+    type Count = Next<<P as DivideOut<Quotient<T, P>>>::Count>;
+    type Rest = <P as DivideOut<Quotient<T, P>>>::Rest;
+}
+
+pub trait FactorFrom<T> {
+    type Result;
+}
+
+impl<D, T> FactorFrom<T> for D
+where
+    T: Compare<One>,
+    Ordering<T, One>: OrderingPredicates,
+    IsEqual<T, One>: FactorStep<D, T>,
+{
+    type Result = <IsEqual<T, One> as FactorStep<D, T>>::Result;
+}
+
+pub trait FactorStep<D, T> {
+    type Result;
+}
+
+impl<D, T> FactorStep<D, T> for True {
+    type Result = Nil;
+}
+
+impl<D, T> FactorStep<D, T> for False
+where
+    D: TrialDivision<T>,
+    <D as TrialDivision<T>>::Result: DivideOut<T>,
+    Next<<D as TrialDivision<T>>::Result>:
+        FactorFrom<<<D as TrialDivision<T>>::Result as DivideOut<T>>::Rest>,
+{
+    type Result = Cons<
+        (
+            <D as TrialDivision<T>>::Result,
+            <<D as TrialDivision<T>>::Result as DivideOut<T>>::Count,
+        ),
+        <Next<<D as TrialDivision<T>>::Result> as FactorFrom<
+            <<D as TrialDivision<T>>::Result as DivideOut<T>>::Rest,
+        >>::Result,
+    >;
+}
+
+/// Type-level prime factorisation.
+pub trait PrimeFactors {
+    /// The prime factorisation of `Self`.
+    type Result;
+}
+
+impl<T> PrimeFactors for T
+where
+    T: Positive + Simplify,
+    Two: FactorFrom<Simplified<T>>,
+{
+    type Result = <Two as FactorFrom<Simplified<T>>>::Result;
+}
+
+/// The prime factorisation of `T`.
+///
+/// The result is a type-level list of `(Prime, Multiplicity)` pairs in
+/// ascending order of the primes. Only implemented for positive integers;
+/// the factorisation of one is [`Nil`].
+pub type Factorize<T> = <T as PrimeFactors>::Result;
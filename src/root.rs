@@ -0,0 +1,250 @@
+use crate::cmp::OrderingPredicates;
+use crate::Compare;
+use crate::Difference;
+use crate::Equal;
+use crate::Exp;
+use crate::Exponent;
+use crate::False;
+use crate::Fraction;
+use crate::Gcd;
+use crate::GreatestCommonDivisor;
+use crate::IsEqual;
+use crate::IsGreater;
+use crate::IsGreaterOrEqual;
+use crate::IsLess;
+use crate::Negation;
+use crate::Negative;
+use crate::Next;
+use crate::NonNegative;
+use crate::Not;
+use crate::One;
+use crate::Ordering;
+use crate::Positive;
+use crate::Prev;
+use crate::Product;
+use crate::Quotient;
+use crate::Remainder;
+use crate::Simplified;
+use crate::Simplify;
+use crate::Sum;
+use crate::True;
+use crate::Two;
+use crate::Zero;
+
+use core::ops::Add;
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Rem;
+use core::ops::Sub;
+
+use local_type_alias::local_alias;
+
+pub trait SqrtFrom<Root, Odd> {
+    type Result;
+}
+
+impl<T, Root, Odd> SqrtFrom<Root, Odd> for T
+where
+    T: Compare<Odd>,
+    Ordering<T, Odd>: OrderingPredicates,
+    IsLess<T, Odd>: Not,
+    IsGreaterOrEqual<T, Odd>: SqrtStep<T, Root, Odd>,
+{
+    type Result = <IsGreaterOrEqual<T, Odd> as SqrtStep<T, Root, Odd>>::Result;
+}
+
+pub trait SqrtStep<T, Root, Odd> {
+    type Result;
+}
+
+impl<T, Root, Odd> SqrtStep<T, Root, Odd> for False {
+    type Result = Root;
+}
+
+impl<T, Root, Odd> SqrtStep<T, Root, Odd> for True
+where
+    T: Sub<Odd>,
+    Odd: Add<Two>,
+    Difference<T, Odd>: SqrtFrom<Next<Root>, Sum<Odd, Two>>,
+{
+    type Result = <Difference<T, Odd> as SqrtFrom<Next<Root>, Sum<Odd, Two>>>::Result;
+}
+
+/// Type-level integer square root.
+///
+/// Only integers are supported. The square root of a [`Fraction`] can be taken
+/// exactly with [`Root<T, Two>`](Root), or by raising it to the power of
+/// `Fraction<One, Two>`.
+pub trait SquareRoot {
+    /// The square root of `Self`, rounded down.
+    type Result;
+}
+
+impl<T> SquareRoot for T
+where
+    T: NonNegative + SqrtFrom<Zero, One>,
+{
+    type Result = <T as SqrtFrom<Zero, One>>::Result;
+}
+
+/// The square root of `T`, rounded down.
+///
+/// Only implemented for non-negative integers.
+pub type Sqrt<T> = <T as SquareRoot>::Result;
+
+/// Whether `T` is the square of an integer.
+pub type IsPerfectSquare<T> = IsEqual<Product<Sqrt<T>, Sqrt<T>>, T>;
+
+/// Type-level exact square root.
+pub trait ExactSquareRoot {
+    /// The square root of `Self`.
+    type Result;
+}
+
+impl<T> ExactSquareRoot for T
+where
+    T: SquareRoot,
+    Sqrt<T>: Mul<Sqrt<T>>,
+    Product<Sqrt<T>, Sqrt<T>>: Compare<T, Result = Equal>,
+{
+    type Result = Sqrt<T>;
+}
+
+/// The square root of `T`.
+///
+/// Fails to compile if `T` is not the square of an integer. For fractions,
+/// use [`Root<T, Two>`](Root) instead.
+pub type ExactSqrt<T> = <T as ExactSquareRoot>::Result;
+
+pub trait PowerOf<T> {
+    type Result;
+}
+
+impl<T> PowerOf<T> for Zero {
+    type Result = One;
+}
+
+impl<T, K> PowerOf<T> for Next<K>
+where
+    K: PowerOf<T>,
+    <K as PowerOf<T>>::Result: Mul<T>,
+{
+    type Result = Product<<K as PowerOf<T>>::Result, T>;
+}
+
+pub trait RootFrom<K, Root> {
+    type Result;
+}
+
+impl<T, K, Root> RootFrom<K, Root> for T
+where
+    K: PowerOf<Next<Root>>,
+    <K as PowerOf<Next<Root>>>::Result: Compare<T>,
+    Ordering<<K as PowerOf<Next<Root>>>::Result, T>: OrderingPredicates,
+    IsGreater<<K as PowerOf<Next<Root>>>::Result, T>: RootStep<T, K, Root>,
+{
+    type Result =
+        <IsGreater<<K as PowerOf<Next<Root>>>::Result, T> as RootStep<T, K, Root>>::Result;
+}
+
+pub trait RootStep<T, K, Root> {
+    type Result;
+}
+
+impl<T, K, Root> RootStep<T, K, Root> for True {
+    type Result = Root;
+}
+
+impl<T, K, Root> RootStep<T, K, Root> for False
+where
+    T: RootFrom<K, Next<Root>>,
+{
+    type Result = <T as RootFrom<K, Next<Root>>>::Result;
+}
+
+/// Type-level exact roots.
+pub trait NthRoot<K> {
+    /// The `K`th root of `Self`.
+    type Result;
+}
+
+impl<K: Positive> NthRoot<K> for Zero {
+    type Result = Zero;
+}
+
+#[local_alias]
+#[alias(type FloorRoot = <Next<T> as RootFrom<Simplified<K>, Zero>>::Result)]
+impl<T, K> NthRoot<K> for Next<T>
+where
+    K: Positive + Simplify,
+    Next<T>: Positive + RootFrom<Simplified<K>, Zero>,
+    Simplified<K>: PowerOf<FloorRoot>,
+    <Simplified<K> as PowerOf<FloorRoot>>::Result: Compare<Next<T>, Result = Equal>,
+{
+    type Result = FloorRoot;
+}
+
+impl<T, K> NthRoot<K> for Prev<T>
+where
+    K: Positive + Rem<Two>,
+    Remainder<K, Two>: Compare<One, Result = Equal>,
+    Prev<T>: Negative + Neg,
+    Negation<Prev<T>>: NthRoot<K>,
+    Root<Negation<Prev<T>>, K>: Neg,
+{
+    type Result = Negation<Root<Negation<Prev<T>>, K>>;
+}
+
+#[local_alias]
+#[alias(
+    type ReducedNum = Quotient<Num, GreatestCommonDivisor<Num, Dem>>,
+    type ReducedDem = Quotient<Dem, GreatestCommonDivisor<Num, Dem>>,
+)]
+impl<Num, Dem, K> NthRoot<K> for Fraction<Num, Dem>
+where
+    Dem: Positive,
+    Num: Gcd<Dem> + Div<GreatestCommonDivisor<Num, Dem>>,
+    Dem: Div<GreatestCommonDivisor<Num, Dem>>,
+    ReducedNum: NthRoot<K>,
+    ReducedDem: NthRoot<K>,
+    Root<ReducedDem, K>: Positive,
+{
+    type Result = Fraction<Root<ReducedNum, K>, Root<ReducedDem, K>>;
+}
+
+/// The `K`th root of `T`.
+///
+/// Fails to compile if the root is not rational, or if `T` is negative and
+/// `K` is even.
+pub type Root<T, K> = <T as NthRoot<K>>::Result;
+
+macro_rules! impl_rational_exp {
+    ($(<$($param:ident),*> $base:ty $([$($bound:tt)*])?);* $(;)?) => {
+        $(
+            #[local_alias]
+            #[alias(
+                type ReducedNum = Quotient<Num, GreatestCommonDivisor<Num, Dem>>,
+                type ReducedDem = Quotient<Dem, GreatestCommonDivisor<Num, Dem>>,
+            )]
+            impl<Num, Dem, $($param),*> Exp<Fraction<Num, Dem>> for $base
+            where
+                Dem: Positive,
+                Num: Gcd<Dem> + Div<GreatestCommonDivisor<Num, Dem>>,
+                Dem: Div<GreatestCommonDivisor<Num, Dem>>,
+                $base: NthRoot<ReducedDem>,
+                Root<$base, ReducedDem>: Exp<ReducedNum>,
+                $($($bound)*)?
+            {
+                type Result = Exponent<Root<$base, ReducedDem>, ReducedNum>;
+            }
+        )*
+    };
+}
+
+impl_rational_exp![
+    <> Zero;
+    <T> Next<T>;
+    <T> Prev<T>;
+    <BaseNum, BaseDem> Fraction<BaseNum, BaseDem> [BaseDem: Positive];
+];
@@ -4,6 +4,14 @@ fn reify_i32<T: Reify<i32>>(_: T) -> i32 {
     T::REIFIED
 }
 
+fn reify_ordering<T: Reify<core::cmp::Ordering>>(_: T) -> core::cmp::Ordering {
+    T::REIFIED
+}
+
+fn reify_bool<T: Reify<bool>>(_: T) -> bool {
+    T::REIFIED
+}
+
 fn reify_i32_i32<T: Reify<(i32, i32)>>(_: T) -> (i32, i32) {
     T::REIFIED
 }
@@ -60,6 +68,88 @@ fn div() {
     assert_eq!(reify_i32(Remainder::<Next<Ten>, Four>::VALUE), 3);
     assert_eq!(reify_i32(Quotient::<Four, Four>::VALUE), 1);
     assert_eq!(reify_i32(Remainder::<Four, Four>::VALUE), 0);
+    assert_eq!(reify_i32(Quotient::<Negation<Seven>, Two>::VALUE), -7 / 2);
+    assert_eq!(reify_i32(Remainder::<Negation<Seven>, Two>::VALUE), -7 % 2);
+    assert_eq!(reify_i32(Quotient::<Seven, Negation<Two>>::VALUE), 7 / -2);
+    assert_eq!(reify_i32(Remainder::<Seven, Negation<Two>>::VALUE), 7 % -2);
+    assert_eq!(
+        reify_i32(Quotient::<Negation<Seven>, Negation<Two>>::VALUE),
+        -7 / -2
+    );
+    assert_eq!(
+        reify_i32(Remainder::<Negation<Seven>, Negation<Two>>::VALUE),
+        -7 % -2
+    );
+    assert_eq!(reify_i32(Quotient::<Negation<One>, Three>::VALUE), 0);
+    assert_eq!(reify_i32(Remainder::<Negation<One>, Three>::VALUE), -1);
+}
+
+#[test]
+fn rounding_div() {
+    assert_eq!(reify_i32(FloorQuotient::<Seven, Two>::VALUE), 3);
+    assert_eq!(reify_i32(FloorQuotient::<Negation<Seven>, Two>::VALUE), -4);
+    assert_eq!(reify_i32(FloorQuotient::<Seven, Negation<Two>>::VALUE), -4);
+    assert_eq!(reify_i32(FloorQuotient::<Negation<Six>, Two>::VALUE), -3);
+    assert_eq!(reify_i32(CeilQuotient::<Seven, Two>::VALUE), 4);
+    assert_eq!(reify_i32(CeilQuotient::<Negation<Seven>, Two>::VALUE), -3);
+    assert_eq!(
+        reify_i32(CeilQuotient::<Negation<Seven>, Negation<Two>>::VALUE),
+        4
+    );
+    assert_eq!(reify_i32(CeilQuotient::<Six, Three>::VALUE), 2);
+    assert_eq!(reify_i32(RoundQuotient::<Seven, Two>::VALUE), 4);
+    assert_eq!(reify_i32(RoundQuotient::<Seven, Three>::VALUE), 2);
+    assert_eq!(reify_i32(RoundQuotient::<Eight, Three>::VALUE), 3);
+    assert_eq!(reify_i32(RoundQuotient::<Negation<Seven>, Two>::VALUE), -4);
+    assert_eq!(
+        reify_i32(RoundQuotient::<Negation<Seven>, Three>::VALUE),
+        -2
+    );
+    assert_eq!(
+        reify_i32(EuclidQuotient::<Negation<Seven>, Two>::VALUE),
+        (-7i32).div_euclid(2)
+    );
+    assert_eq!(
+        reify_i32(EuclidRemainder::<Negation<Seven>, Two>::VALUE),
+        (-7i32).rem_euclid(2)
+    );
+    assert_eq!(
+        reify_i32(EuclidQuotient::<Negation<Seven>, Negation<Two>>::VALUE),
+        (-7i32).div_euclid(-2)
+    );
+    assert_eq!(
+        reify_i32(EuclidRemainder::<Negation<Seven>, Negation<Two>>::VALUE),
+        (-7i32).rem_euclid(-2)
+    );
+    assert_eq!(
+        reify_i32(EuclidQuotient::<Seven, Negation<Two>>::VALUE),
+        7i32.div_euclid(-2)
+    );
+    assert_eq!(
+        reify_i32(EuclidRemainder::<Seven, Negation<Two>>::VALUE),
+        7i32.rem_euclid(-2)
+    );
+
+    assert_eq!(
+        reify_i32(FloorQuotient::<Next<Prev<Eight>>, Three>::VALUE),
+        2
+    );
+    assert_eq!(
+        reify_i32(CeilQuotient::<Next<Prev<Eight>>, Three>::VALUE),
+        3
+    );
+    assert_eq!(
+        reify_i32(RoundQuotient::<Next<Prev<Eight>>, Three>::VALUE),
+        3
+    );
+    assert_eq!(
+        reify_i32(EuclidQuotient::<Negation<Next<Prev<Eight>>>, Three>::VALUE),
+        -3
+    );
+    assert_eq!(
+        reify_i32(EuclidRemainder::<Seven, Prev<Next<Three>>>::VALUE),
+        1
+    );
 }
 
 #[test]
@@ -67,6 +157,81 @@ fn gcd() {
     assert_eq!(reify_i32(GreatestCommonDivisor::<Six, Three>::VALUE), 3);
     assert_eq!(reify_i32(GreatestCommonDivisor::<Ten, Three>::VALUE), 1);
     assert_eq!(reify_i32(GreatestCommonDivisor::<Five, Five>::VALUE), 5);
+    assert_eq!(
+        reify_i32(GreatestCommonDivisor::<Negation<Six>, Four>::VALUE),
+        2
+    );
+    assert_eq!(
+        reify_i32(GreatestCommonDivisor::<Six, Negation<Nine>>::VALUE),
+        3
+    );
+    assert_eq!(
+        reify_i32(GreatestCommonDivisor::<Negation<Eight>, Negation<Four>>::VALUE),
+        4
+    );
+    assert_eq!(
+        reify_i32(GreatestCommonDivisor::<Next<Prev<Six>>, Four>::VALUE),
+        2
+    );
+    assert_eq!(
+        reify_i32(GreatestCommonDivisor::<Zero, Negation<Seven>>::VALUE),
+        7
+    );
+    assert_eq!(
+        reify_i32(GreatestCommonDivisor::<Prev<Next<Zero>>, Zero>::VALUE),
+        0
+    );
+}
+
+#[test]
+fn extended_gcd() {
+    fn check<A, B>(a: i32, b: i32)
+    where
+        A: ExtendedGcd<B> + Gcd<B>,
+        <A as ExtendedGcd<B>>::Gcd: Reify<i32>,
+        BezoutX<A, B>: Reify<i32>,
+        BezoutY<A, B>: Reify<i32>,
+        GreatestCommonDivisor<A, B>: Reify<i32>,
+    {
+        let gcd = <<A as ExtendedGcd<B>>::Gcd as Reify<i32>>::REIFIED;
+        let x = <BezoutX<A, B> as Reify<i32>>::REIFIED;
+        let y = <BezoutY<A, B> as Reify<i32>>::REIFIED;
+        assert_eq!(a * x + b * y, gcd);
+        assert_eq!(gcd, <GreatestCommonDivisor<A, B> as Reify<i32>>::REIFIED);
+    }
+
+    check::<Ten, Six>(10, 6);
+    check::<Seven, Three>(7, 3);
+    check::<Negation<Nine>, Six>(-9, 6);
+    check::<Eight, Negation<Five>>(8, -5);
+    check::<Zero, Four>(0, 4);
+    check::<Next<Prev<Four>>, Zero>(4, 0);
+    check::<Zero, Zero>(0, 0);
+    check::<Zero, Negation<Three>>(0, -3);
+    check::<Negation<Four>, Zero>(-4, 0);
+    check::<Negation<Four>, Negation<Six>>(-4, -6);
+    assert_eq!(
+        reify_i32(<Negation<Nine> as ExtendedGcd<Six>>::Gcd::VALUE),
+        3
+    );
+}
+
+#[test]
+fn modular() {
+    type Hundred = Product<Ten, Ten>;
+
+    assert_eq!(reify_i32(ModInverse::<Three, Seven>::VALUE), 5);
+    assert_eq!(reify_i32(ModInverse::<Negation<Three>, Seven>::VALUE), 2);
+    assert_eq!(reify_i32(ModInverse::<Ten, Seven>::VALUE), 5);
+    assert_eq!(reify_i32(ModInverse::<Four, One>::VALUE), 0);
+    assert_eq!(reify_i32(ModPow::<Three, Ten, Seven>::VALUE), 4);
+    assert_eq!(reify_i32(ModPow::<Two, Hundred, Seven>::VALUE), 2);
+    assert_eq!(reify_i32(ModPow::<Negation<Two>, Three, Five>::VALUE), 2);
+    assert_eq!(reify_i32(ModPow::<Three, Zero, One>::VALUE), 0);
+    assert_eq!(reify_i32(ModPow::<Three, Negation<Two>, Seven>::VALUE), 4);
+    assert_eq!(reify_i32(ModPow::<Two, Next<Prev<Three>>, Five>::VALUE), 3);
+    assert_eq!(reify_i32(ModPow::<Two, Three, Prev<Next<Five>>>::VALUE), 3);
+    assert_eq!(reify_i32(ModInverse::<Three, Next<Prev<Seven>>>::VALUE), 5);
 }
 
 #[test]
@@ -77,14 +242,655 @@ fn lcm() {
     assert_eq!(reify_i32(LeastCommonMultiple::<Four, Four>::VALUE), 4);
 }
 
+#[test]
+fn sqrt() {
+    type Fifty = Product<Ten, Five>;
+    type FortyNine = Product<Seven, Seven>;
+
+    assert_eq!(reify_i32(Sqrt::<Zero>::VALUE), 0);
+    assert_eq!(reify_i32(Sqrt::<One>::VALUE), 1);
+    assert_eq!(reify_i32(Sqrt::<Eight>::VALUE), 2);
+    assert_eq!(reify_i32(Sqrt::<Nine>::VALUE), 3);
+    assert_eq!(reify_i32(Sqrt::<Fifty>::VALUE), 7);
+    assert_eq!(reify_i32(Sqrt::<Next<Prev<Four>>>::VALUE), 2);
+    assert!(reify_bool(IsPerfectSquare::<FortyNine>::VALUE));
+    assert!(!reify_bool(IsPerfectSquare::<Fifty>::VALUE));
+    assert_eq!(reify_i32(ExactSqrt::<FortyNine>::VALUE), 7);
+}
+
+#[test]
+fn root() {
+    type TwentySeven = Product<Nine, Three>;
+    type Eighteen = Product<Nine, Two>;
+
+    assert_eq!(reify_i32(Root::<TwentySeven, Three>::VALUE), 3);
+    assert_eq!(reify_i32(Root::<Negation<Eight>, Three>::VALUE), -2);
+    assert_eq!(reify_i32(Root::<Zero, Two>::VALUE), 0);
+    assert_eq!(reify_i32(Root::<Seven, One>::VALUE), 7);
+    assert_eq!(
+        reify_i32_i32(Root::<Fraction<Eight, Eighteen>, Two>::VALUE),
+        (2, 3)
+    );
+}
+
+#[test]
+fn rational_exp() {
+    assert_eq!(reify_i32(Exponent::<Eight, Fraction<Two, Three>>::VALUE), 4);
+    assert_eq!(reify_i32(Exponent::<Four, Fraction<Two, Four>>::VALUE), 2);
+    assert_eq!(
+        reify_i32(Exponent::<Negation<Eight>, Fraction<One, Three>>::VALUE),
+        -2
+    );
+    assert_eq!(
+        reify_i32_i32(Exponent::<Four, Fraction<Negation<One>, Two>>::VALUE),
+        (1, 2)
+    );
+    assert_eq!(
+        reify_i32_i32(Exponent::<Fraction<Four, Nine>, Fraction<One, Two>>::VALUE),
+        (2, 3)
+    );
+    assert_eq!(reify_i32(Exponent::<Five, Fraction<Zero, Three>>::VALUE), 1);
+}
+
+#[test]
+fn log() {
+    type SixtyFour = Exponent<Two, Six>;
+
+    assert_eq!(reify_i32(Log2::<One>::VALUE), 0);
+    assert_eq!(reify_i32(CeilLog2::<One>::VALUE), 0);
+    assert_eq!(reify_i32(Log2::<Seven>::VALUE), 2);
+    assert_eq!(reify_i32(CeilLog2::<Seven>::VALUE), 3);
+    assert_eq!(reify_i32(Log2::<SixtyFour>::VALUE), 6);
+    assert_eq!(reify_i32(CeilLog2::<SixtyFour>::VALUE), 6);
+    assert_eq!(reify_i32(LogBase::<Product<Ten, Ten>, Ten>::VALUE), 2);
+    assert_eq!(reify_i32(CeilLogBase::<Ten, Three>::VALUE), 3);
+    assert!(reify_bool(IsPowerOf::<Nine, Three>::VALUE));
+    assert!(!reify_bool(IsPowerOf::<Six, Two>::VALUE));
+    assert_eq!(reify_i32(Log2::<Next<Prev<Eight>>>::VALUE), 3);
+    assert_eq!(reify_i32(CeilLog2::<Next<Prev<Eight>>>::VALUE), 3);
+    assert_eq!(reify_i32(LogBase::<Nine, Prev<Next<Three>>>::VALUE), 2);
+    assert!(reify_bool(IsPowerOf::<Next<Prev<Eight>>, Two>::VALUE));
+}
+
+#[test]
+fn combinatorics() {
+    assert_eq!(reify_i32(Factorial::<Zero>::VALUE), 1);
+    assert_eq!(reify_i32(Factorial::<Five>::VALUE), 120);
+    assert_eq!(reify_i32(Factorial::<Prev<Next<Four>>>::VALUE), 24);
+    assert_eq!(reify_i32(Permutations::<Five, Two>::VALUE), 20);
+    assert_eq!(reify_i32(Permutations::<Five, Zero>::VALUE), 1);
+    assert_eq!(reify_i32(Permutations::<Three, Four>::VALUE), 0);
+    assert_eq!(reify_i32(Binomial::<Six, Two>::VALUE), 15);
+    assert_eq!(reify_i32(Binomial::<Six, Six>::VALUE), 1);
+    assert_eq!(reify_i32(Binomial::<Seven, Next<Prev<Three>>>::VALUE), 35);
+    assert_eq!(reify_i32(Binomial::<Two, Five>::VALUE), 0);
+}
+
+#[test]
+fn signum() {
+    assert_eq!(reify_i32(Signum::<Zero>::VALUE), 0);
+    assert_eq!(reify_i32(Signum::<Seven>::VALUE), 1);
+    assert_eq!(reify_i32(Signum::<Negation<Three>>::VALUE), -1);
+    assert_eq!(reify_i32(Signum::<Next<Prev<Zero>>>::VALUE), 0);
+    assert_eq!(reify_i32(Signum::<Prev<Next<Next<Zero>>>>::VALUE), 1);
+    assert_eq!(
+        reify_i32(Signum::<Fraction<Negation<Two>, Three>>::VALUE),
+        -1
+    );
+    assert_eq!(reify_i32(Signum::<Fraction<Zero, Three>>::VALUE), 0);
+    assert_eq!(
+        reify_i32(Product::<Signum<Negation<Four>>, Five>::VALUE),
+        -5
+    );
+    assert_eq!(
+        reify_i32_i32(Simplified::<Fraction<Zero, Four>>::VALUE),
+        (0, 1)
+    );
+}
+
+#[test]
+fn parity() {
+    fn assert_even<T: Even>() {}
+    fn assert_odd<T: Odd>() {}
+    fn assert_power_of_two<T: PowerOfTwo>() {}
+
+    assert!(reify_bool(IsEven::<Zero>::VALUE));
+    assert!(!reify_bool(IsOdd::<Zero>::VALUE));
+    assert!(reify_bool(IsOdd::<Three>::VALUE));
+    assert!(reify_bool(IsEven::<Negation<Four>>::VALUE));
+    assert!(reify_bool(IsOdd::<Negation<Five>>::VALUE));
+    assert!(reify_bool(IsEven::<Next<Prev<Six>>>::VALUE));
+    assert_even::<Eight>();
+    assert_odd::<Negation<One>>();
+
+    assert!(!reify_bool(IsPowerOfTwo::<Zero>::VALUE));
+    assert!(!reify_bool(IsPowerOfTwo::<Negation<Two>>::VALUE));
+    assert!(reify_bool(IsPowerOfTwo::<One>::VALUE));
+    assert!(reify_bool(IsPowerOfTwo::<Eight>::VALUE));
+    assert!(!reify_bool(IsPowerOfTwo::<Six>::VALUE));
+    assert!(reify_bool(IsPowerOfTwo::<Prev<Next<Four>>>::VALUE));
+    assert_power_of_two::<Sum<Eight, Eight>>();
+}
+
+#[test]
+fn binary() {
+    type BOne = B1<Zero>;
+    type BTwo = B0<BOne>;
+    type BThree = B1<BOne>;
+    type BSeven = B1<BThree>;
+    type BTen = B0<B1<B0<BOne>>>;
+    type BNegThree = B1<B0<Ones>>;
+    type BThousand = B0<B0<B0<B1<B0<B1<B1<B1<B1<BOne>>>>>>>>>;
+
+    assert_eq!(reify_i32(Ones::VALUE), -1);
+    assert_eq!(reify_i32(BNegThree::VALUE), -3);
+    assert_eq!(reify_i32(BThousand::VALUE), 1000);
+    assert_eq!(reify_i32(Negation::<BThousand>::VALUE), -1000);
+    assert_eq!(reify_i32(Negation::<Ones>::VALUE), 1);
+
+    assert_eq!(reify_i32(Sum::<BThousand, BNegThree>::VALUE), 997);
+    assert_eq!(reify_i32(Sum::<Ones, Ones>::VALUE), -2);
+    assert_eq!(reify_i32(Sum::<Zero, BSeven>::VALUE), 7);
+    assert_eq!(reify_i32(Difference::<BThree, BThousand>::VALUE), -997);
+    assert_eq!(reify_i32(Difference::<BSeven, BSeven>::VALUE), 0);
+
+    assert_eq!(reify_i32(Product::<BThousand, BThousand>::VALUE), 1_000_000);
+    assert_eq!(reify_i32(Product::<BNegThree, BSeven>::VALUE), -21);
+    assert_eq!(reify_i32(Product::<Ones, BNegThree>::VALUE), 3);
+
+    assert_eq!(reify_i32(Quotient::<BThousand, BSeven>::VALUE), 142);
+    assert_eq!(reify_i32(Remainder::<BThousand, BSeven>::VALUE), 6);
+    assert_eq!(
+        reify_i32(Quotient::<Negation<BThousand>, BSeven>::VALUE),
+        -142
+    );
+    assert_eq!(
+        reify_i32(Remainder::<Negation<BThousand>, BSeven>::VALUE),
+        -6
+    );
+    assert_eq!(reify_i32(Quotient::<BThousand, BNegThree>::VALUE), -333);
+    assert_eq!(reify_i32(Remainder::<BThousand, BNegThree>::VALUE), 1);
+    assert_eq!(reify_i32(Quotient::<Zero, BSeven>::VALUE), 0);
+    assert_eq!(
+        reify_i32(FloorQuotient::<Negation<BThousand>, BSeven>::VALUE),
+        -143
+    );
+    assert_eq!(reify_i32(FloorQuotient::<BOne, BNegThree>::VALUE), -1);
+    assert_eq!(reify_i32(CeilQuotient::<BThousand, BSeven>::VALUE), 143);
+    assert_eq!(reify_i32(CeilQuotient::<BTwo, BThree>::VALUE), 1);
+    assert_eq!(reify_i32(RoundQuotient::<BThousand, BThree>::VALUE), 333);
+    assert_eq!(reify_i32(RoundQuotient::<BTen, BNegThree>::VALUE), -3);
+    assert_eq!(reify_i32(RoundQuotient::<BSeven, BTwo>::VALUE), 4);
+    assert_eq!(
+        reify_i32(EuclidQuotient::<BNegThree, BSeven>::VALUE),
+        (-3i32).div_euclid(7)
+    );
+    assert_eq!(
+        reify_i32(EuclidRemainder::<BNegThree, BSeven>::VALUE),
+        (-3i32).rem_euclid(7)
+    );
+    assert_eq!(
+        reify_i32(EuclidQuotient::<Negation<BThousand>, Ones>::VALUE),
+        1000
+    );
+    assert_eq!(reify_i32(EuclidRemainder::<BTen, Ones>::VALUE), 0);
+
+    assert_eq!(
+        reify_i32(
+            GreatestCommonDivisor::<Product<BThousand, BThree>, Product<BSeven, BThousand>>::VALUE
+        ),
+        1000
+    );
+    assert_eq!(
+        reify_i32(GreatestCommonDivisor::<Zero, BNegThree>::VALUE),
+        3
+    );
+    assert_eq!(
+        reify_i32(LeastCommonMultiple::<B0<BThree>, BNegThree>::VALUE),
+        6
+    );
+    assert_eq!(reify_i32(Absolute::<BNegThree>::VALUE), 3);
+    assert_eq!(Signum::<BThousand>::VALUE, BOne::VALUE);
+    assert_eq!(Signum::<BNegThree>::VALUE, Ones::VALUE);
+    assert_eq!(Signum::<Ones>::VALUE, Ones::VALUE);
+    assert_eq!(reify_i32(Signum::<BOne>::VALUE), 1);
+    assert_eq!(reify_i32(Product::<Signum<BNegThree>, BSeven>::VALUE), -7);
+
+    assert_eq!(
+        reify_ordering(Ordering::<BThousand, BSeven>::VALUE),
+        core::cmp::Ordering::Greater
+    );
+    assert!(reify_bool(IsNegative::<BNegThree>::VALUE));
+    assert!(reify_bool(IsZero::<Difference<BTen, BTen>>::VALUE));
+
+    assert_eq!(Simplified::<B0<B0<Zero>>>::VALUE, Zero::VALUE);
+    assert_eq!(Simplified::<B1<B1<Ones>>>::VALUE, Ones::VALUE);
+    assert_eq!(Simplified::<B1<B0<Zero>>>::VALUE, BOne::VALUE);
+}
+
+#[test]
+fn binary_exp() {
+    type BTwo = B0<B1<Zero>>;
+    type BThree = B1<B1<Zero>>;
+    type BSeven = B1<BThree>;
+    type BTen = B0<B1<BTwo>>;
+    type BNegThree = B1<B0<Ones>>;
+
+    assert_eq!(reify_i32(Exponent::<BTwo, BTen>::VALUE), 1024);
+    assert_eq!(reify_i32(Exponent::<BNegThree, BThree>::VALUE), -27);
+    assert_eq!(reify_i32(Exponent::<BSeven, Zero>::VALUE), 1);
+    assert_eq!(reify_i32(Exponent::<Zero, BSeven>::VALUE), 0);
+    assert_eq!(
+        reify_i32_i32(Exponent::<BTwo, Negation<BThree>>::VALUE),
+        (1, 8)
+    );
+    assert_eq!(reify_i32_i32(Exponent::<BNegThree, Ones>::VALUE), (-1, 3));
+    assert_eq!(
+        reify_i32_i32(Exponent::<BNegThree, Negation<BTwo>>::VALUE),
+        (1, 9)
+    );
+    assert_eq!(
+        reify_i32_i32(Exponent::<Ones, Negation<BSeven>>::VALUE),
+        (-1, 1)
+    );
+    assert_eq!(reify_i32_i32(Reciprocal::<BNegThree>::VALUE), (-1, 3));
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn macros() {
+    macro_rules! forward {
+        ($value:literal) => {
+            peano!($value)
+        };
+    }
+
+    assert_eq!(<peano!(0)>::VALUE, Zero::VALUE);
+    assert_eq!(<peano!(7)>::VALUE, Seven::VALUE);
+    assert_eq!(<peano!(-3)>::VALUE, Negation::<Three>::VALUE);
+    assert_eq!(<peano!(0b1010)>::VALUE, Ten::VALUE);
+    assert_eq!(<forward!(4)>::VALUE, Four::VALUE);
+    assert_eq!(<forward!(-4)>::VALUE, Negation::<Four>::VALUE);
+    assert_eq!(<peano!(1_00) as Integer>::I32, 100);
+    assert_eq!(
+        core::any::type_name::<peano!(1234)>()
+            .matches("Next")
+            .count(),
+        1234
+    );
+    assert_eq!(<peano!(-17i8) as Integer>::I8, -17);
+
+    assert_eq!(<peano_binary!(0)>::VALUE, Zero::VALUE);
+    assert_eq!(<peano_binary!(-1)>::VALUE, Ones::VALUE);
+    assert_eq!(<peano_binary!(6)>::VALUE, B0::<B1<B1<Zero>>>::VALUE);
+    assert_eq!(<peano_binary!(-3)>::VALUE, B1::<B0<Ones>>::VALUE);
+    assert_eq!(
+        <peano_binary!(-170_141_183_460_469_231_731_687_303_715_884_105_728) as Integer>::I128,
+        i128::MIN
+    );
+    assert_eq!(
+        reify_i32(Product::<peano_binary!(1234), peano_binary!(-1000)>::VALUE),
+        -1_234_000
+    );
+    assert_eq!(ToUnary::<peano_binary!(-17)>::VALUE, <peano!(-17)>::VALUE);
+}
+
+#[test]
+fn integer() {
+    const fn classify<T: Integer>() -> &'static str {
+        match T::I32 {
+            0 => "zero",
+            1..=9 => "digit",
+            _ => "other",
+        }
+    }
+
+    let buffer = [0u8; <Product<Three, Four> as Integer>::USIZE];
+    assert_eq!(buffer.len(), 12);
+
+    assert_eq!(<Zero as Integer>::U8, 0);
+    assert_eq!(<Seven as Integer>::I8, 7);
+    assert_eq!(<Negation<Five> as Integer>::I64, -5);
+    assert_eq!(<Next<Prev<Two>> as Integer>::I128, 2);
+    assert_eq!(<Ten as Integer>::U128, 10);
+    assert_eq!(<B1<B0<Ones>> as Integer>::ISIZE, -3);
+    assert_eq!(<B0<B1<B1<Zero>>> as Integer>::U16, 6);
+    assert_eq!(<Ones as Integer>::I32, -1);
+    assert_eq!(classify::<Zero>(), "zero");
+    assert_eq!(classify::<Four>(), "digit");
+    assert_eq!(classify::<Negation<One>>(), "other");
+}
+
+#[test]
+fn const_generic() {
+    fn peano_len<T, const N: usize>(_: [T; N]) -> i32
+    where
+        ConstUsize<N>: ConstToPeano,
+        Usize<N>: Reify<i32>,
+    {
+        Usize::<N>::REIFIED
+    }
+
+    assert_eq!(Int::<0>::VALUE, Zero::VALUE);
+    assert_eq!(Int::<7>::VALUE, Seven::VALUE);
+    assert_eq!(Int::<-3>::VALUE, Negation::<Three>::VALUE);
+    assert_eq!(Usize::<10>::VALUE, Ten::VALUE);
+    assert_eq!(reify_i32(Int::<128>::VALUE), 128);
+    assert_eq!(reify_i32(Int::<-128>::VALUE), -128);
+    assert_eq!(reify_i32(Sum::<Int<-20>, Int<50>>::VALUE), 30);
+    assert_eq!(peano_len([0u8; 12]), 12);
+}
+
+#[test]
+fn conversion() {
+    type BThree = B1<B1<Zero>>;
+    type BNegSix = B0<B1<B0<Ones>>>;
+    type BHalf = ToBinary<Fraction<One, Two>>;
+    type BThird = ToBinary<Fraction<One, Three>>;
+
+    assert_eq!(ToBinary::<Zero>::VALUE, Zero::VALUE);
+    assert_eq!(ToBinary::<Six>::VALUE, B0::<BThree>::VALUE);
+    assert_eq!(ToBinary::<Negation<Six>>::VALUE, BNegSix::VALUE);
+    assert_eq!(ToBinary::<Negation<One>>::VALUE, Ones::VALUE);
+    assert_eq!(ToBinary::<Next<Prev<Three>>>::VALUE, BThree::VALUE);
+    assert_eq!(ToBinary::<B1<B1<Ones>>>::VALUE, Ones::VALUE);
+
+    assert_eq!(ToUnary::<BThree>::VALUE, Three::VALUE);
+    assert_eq!(ToUnary::<BNegSix>::VALUE, Negation::<Six>::VALUE);
+    assert_eq!(ToUnary::<Ones>::VALUE, Negation::<One>::VALUE);
+    assert_eq!(ToUnary::<Prev<Next<Five>>>::VALUE, Five::VALUE);
+    assert_eq!(
+        ToUnary::<Sum<ToBinary<Seven>, ToBinary<Eight>>>::VALUE,
+        Sum::<Seven, Eight>::VALUE
+    );
+
+    assert_eq!(
+        ToBinary::<Fraction<Negation<Three>, Two>>::VALUE,
+        Fraction::<B1<B0<Ones>>, B0<B1<Zero>>>::VALUE
+    );
+    assert_eq!(
+        ToUnary::<ToBinary<Fraction<Five, Four>>>::VALUE,
+        Fraction::<Five, Four>::VALUE
+    );
+
+    assert_eq!(reify_i32_i32(Sum::<BHalf, BThird>::VALUE), (5, 6));
+    assert_eq!(reify_i32_i32(Product::<BHalf, BThird>::VALUE), (1, 6));
+    assert_eq!(
+        reify_i32_i32(Product::<ToBinary<Fraction<Negation<Two>, Three>>, BHalf>::VALUE),
+        (-1, 3)
+    );
+}
+
+#[test]
+fn bitwise() {
+    type Twelve = Product<Three, Four>;
+
+    assert_eq!(reify_i32(BitwiseAnd::<Twelve, Ten>::VALUE), 0b1100 & 0b1010);
+    assert_eq!(reify_i32(BitwiseOr::<Twelve, Ten>::VALUE), 0b1100 | 0b1010);
+    assert_eq!(reify_i32(BitwiseXor::<Twelve, Ten>::VALUE), 0b1100 ^ 0b1010);
+    assert_eq!(reify_i32(BitwiseAnd::<Zero, Seven>::VALUE), 0);
+    assert_eq!(reify_i32(BitwiseOr::<Zero, Seven>::VALUE), 7);
+    assert_eq!(reify_i32(BitwiseAnd::<Negation<Six>, Seven>::VALUE), -6 & 7);
+    assert_eq!(reify_i32(BitwiseOr::<Negation<Six>, Three>::VALUE), -6 | 3);
+    assert_eq!(
+        reify_i32(BitwiseXor::<Negation<Five>, Three>::VALUE),
+        -5 ^ 3
+    );
+    assert_eq!(
+        reify_i32(BitwiseAnd::<Negation<Six>, Negation<Three>>::VALUE),
+        -6 & -3
+    );
+    assert_eq!(reify_i32(BitwiseXor::<Negation<One>, Nine>::VALUE), -1 ^ 9);
+    assert_eq!(
+        reify_i32(BitwiseAnd::<Next<Prev<Six>>, Three>::VALUE),
+        6 & 3
+    );
+
+    assert_eq!(reify_i32(LeftShift::<Three, Two>::VALUE), 3 << 2);
+    assert_eq!(reify_i32(LeftShift::<Negation<Three>, One>::VALUE), -3 << 1);
+    assert_eq!(reify_i32(LeftShift::<Five, Zero>::VALUE), 5);
+    assert_eq!(reify_i32(RightShift::<Ten, Two>::VALUE), 10 >> 2);
+    assert_eq!(
+        reify_i32(RightShift::<Negation<Seven>, One>::VALUE),
+        -7 >> 1
+    );
+    assert_eq!(reify_i32(RightShift::<Negation<One>, Three>::VALUE), -1);
+}
+
+#[test]
+fn digits() {
+    type Twelve = Product<Three, Four>;
+    type Eleven = Next<Ten>;
+
+    assert_eq!(Digits::<Zero, Ten>::VALUE, Cons::<Zero, Nil>::VALUE);
+    assert_eq!(Digits::<Seven, Ten>::VALUE, Cons::<Seven, Nil>::VALUE);
+    assert_eq!(
+        Digits::<Twelve, Ten>::VALUE,
+        Cons::<One, Cons<Two, Nil>>::VALUE
+    );
+    assert_eq!(
+        Digits::<Eleven, Two>::VALUE,
+        Cons::<One, Cons<Zero, Cons<One, Cons<One, Nil>>>>::VALUE
+    );
+    assert_eq!(
+        Digits::<Prev<Next<Five>>, Three>::VALUE,
+        Cons::<One, Cons<Two, Nil>>::VALUE
+    );
+
+    assert_eq!(reify_i32(NumDigits::<Zero, Ten>::VALUE), 1);
+    assert_eq!(reify_i32(NumDigits::<Eleven, Two>::VALUE), 4);
+    assert_eq!(reify_i32(NumDigits::<Twelve, Ten>::VALUE), 2);
+
+    assert_eq!(reify_i32(DigitSum::<Eleven, Two>::VALUE), 3);
+    assert_eq!(reify_i32(DigitSum::<Twelve, Ten>::VALUE), 3);
+    assert_eq!(reify_i32(Length::<Nil>::VALUE), 0);
+}
+
+#[test]
+fn divisor() {
+    type Twelve = Product<Three, Four>;
+
+    fn assert_divisible<T: Divisible<U>, U>() {}
+
+    assert!(reify_bool(Divides::<Three, Twelve>::VALUE));
+    assert!(!reify_bool(Divides::<Five, Twelve>::VALUE));
+    assert!(reify_bool(Divides::<Negation<Four>, Twelve>::VALUE));
+    assert!(reify_bool(Divides::<Two, Negation<Six>>::VALUE));
+    assert!(reify_bool(Divides::<Seven, Zero>::VALUE));
+    assert!(reify_bool(Divides::<Zero, Zero>::VALUE));
+    assert!(!reify_bool(Divides::<Zero, Two>::VALUE));
+    assert!(reify_bool(Divides::<Next<Prev<Three>>, Nine>::VALUE));
+    assert_divisible::<Twelve, Four>();
+
+    assert!(reify_bool(Coprime::<Eight, Nine>::VALUE));
+    assert!(!reify_bool(Coprime::<Six, Nine>::VALUE));
+    assert!(reify_bool(Coprime::<Negation<Five>, Seven>::VALUE));
+
+    assert_eq!(reify_i32(NumDivisors::<One>::VALUE), 1);
+    assert_eq!(reify_i32(NumDivisors::<Seven>::VALUE), 2);
+    assert_eq!(reify_i32(NumDivisors::<Twelve>::VALUE), 6);
+
+    assert_eq!(reify_i32(Totient::<One>::VALUE), 1);
+    assert_eq!(reify_i32(Totient::<Seven>::VALUE), 6);
+    assert_eq!(reify_i32(Totient::<Twelve>::VALUE), 4);
+    assert_eq!(reify_i32(Totient::<Nine>::VALUE), 6);
+}
+
+#[test]
+fn prime() {
+    type Sixty = Product<Six, Ten>;
+
+    fn assert_prime<T: Prime>() {}
+
+    assert!(!reify_bool(IsPrime::<Zero>::VALUE));
+    assert!(!reify_bool(IsPrime::<One>::VALUE));
+    assert!(reify_bool(IsPrime::<Two>::VALUE));
+    assert!(reify_bool(IsPrime::<Seven>::VALUE));
+    assert!(!reify_bool(IsPrime::<Nine>::VALUE));
+    assert!(!reify_bool(IsPrime::<Negation<Seven>>::VALUE));
+    assert!(reify_bool(IsPrime::<Sum<Ten, Three>>::VALUE));
+    assert!(!reify_bool(IsPrime::<Next<Prev<Nine>>>::VALUE));
+    assert!(reify_bool(IsPrime::<Prev<Next<Seven>>>::VALUE));
+    assert_prime::<Five>();
+
+    assert_eq!(Factorize::<One>::VALUE, Nil::VALUE);
+    assert_eq!(Factorize::<Seven>::VALUE, Cons::<(Seven, One), Nil>::VALUE);
+    assert_eq!(
+        Factorize::<Sixty>::VALUE,
+        Cons::<(Two, Two), Cons<(Three, One), Cons<(Five, One), Nil>>>::VALUE
+    );
+    assert_eq!(
+        Factorize::<Next<Prev<Eight>>>::VALUE,
+        Cons::<(Two, Three), Nil>::VALUE
+    );
+}
+
 #[test]
 fn fraction() {
     assert_eq!(reify_i32_i32(Fraction::<Three, Two>::VALUE), (3, 2));
     assert_eq!(reify_i32_i32(<rpn!(1 2 fract 1 3 fract +)>::VALUE), (5, 6));
     assert_eq!(reify_i32_i32(<rpn!(2 3 fract 1 3 fract /)>::VALUE), (2, 1));
+    assert_eq!(reify_i32_i32(<rpn!(2 3 fract 3 *)>::VALUE), (2, 1));
+    assert_eq!(reify_i32_i32(<rpn!(2 3 fract 2 ^)>::VALUE), (4, 9));
+}
+
+#[test]
+fn compare() {
+    use core::cmp::Ordering::{Equal, Greater, Less};
+
+    assert_eq!(reify_ordering(Ordering::<Two, Five>::VALUE), Less);
+    assert_eq!(reify_ordering(Ordering::<Five, Five>::VALUE), Equal);
+    assert_eq!(
+        reify_ordering(Ordering::<Zero, Negation<Three>>::VALUE),
+        Greater
+    );
+    assert_eq!(
+        reify_ordering(Ordering::<Negation<Four>, Negation<Two>>::VALUE),
+        Less
+    );
+    assert_eq!(
+        reify_ordering(Ordering::<Next<Prev<Three>>, Three>::VALUE),
+        Equal
+    );
+    assert_eq!(
+        reify_ordering(Ordering::<Prev<Next<Zero>>, Zero>::VALUE),
+        Equal
+    );
+    assert_eq!(
+        reify_ordering(Ordering::<Fraction<One, Two>, Fraction<Two, Three>>::VALUE),
+        Less
+    );
+    assert_eq!(
+        reify_ordering(Ordering::<Fraction<Four, Two>, Two>::VALUE),
+        Equal
+    );
+    assert_eq!(
+        reify_ordering(Ordering::<Negation<One>, Fraction<Negation<Three>, Two>>::VALUE),
+        Greater
+    );
+}
+
+#[test]
+fn max_min_clamp() {
+    assert_eq!(reify_i32(Maximum::<Three, Seven>::VALUE), 7);
+    assert_eq!(reify_i32(Maximum::<Negation<Three>, Zero>::VALUE), 0);
+    assert_eq!(reify_i32(Minimum::<Three, Seven>::VALUE), 3);
+    assert_eq!(
+        reify_i32(Minimum::<Negation<Three>, Negation<Two>>::VALUE),
+        -3
+    );
+    assert_eq!(
+        reify_i32_i32(Maximum::<Fraction<One, Two>, Fraction<Two, Three>>::VALUE),
+        (2, 3)
+    );
+    assert_eq!(reify_i32(Maximum::<Fraction<One, Two>, One>::VALUE), 1);
+    assert_eq!(reify_i32(Clamped::<Ten, Two, Five>::VALUE), 5);
+    assert_eq!(reify_i32(Clamped::<Negation<Ten>, Two, Five>::VALUE), 2);
+    assert_eq!(reify_i32(Clamped::<Four, Two, Five>::VALUE), 4);
+    assert_eq!(
+        reify_i32_i32(Clamped::<Fraction<Seven, Two>, Zero, Four>::VALUE),
+        (7, 2)
+    );
+}
+
+#[test]
+fn logic() {
+    assert!(reify_bool(Complement::<False>::VALUE));
+    assert!(!reify_bool(Conjunction::<True, False>::VALUE));
+    assert!(reify_bool(Disjunction::<False, True>::VALUE));
+    assert!(!reify_bool(ExclusiveDisjunction::<True, True>::VALUE));
+    assert!(reify_bool(ExclusiveDisjunction::<False, True>::VALUE));
+}
+
+#[test]
+fn conditional() {
+    type MyAbs<X> = If<IsNegative<X>, Negation<X>, X>;
+
+    assert_eq!(reify_i32(If::<True, One, Two>::VALUE), 1);
+    assert_eq!(reify_i32(If::<False, One, Two>::VALUE), 2);
+    assert_eq!(reify_i32(MyAbs::<Negation<Six>>::VALUE), 6);
+    assert_eq!(reify_i32(MyAbs::<Six>::VALUE), 6);
+}
+
+#[test]
+fn predicates() {
+    assert!(reify_bool(IsEqual::<Next<Prev<Four>>, Four>::VALUE));
+    assert!(!reify_bool(IsNotEqual::<Two, Two>::VALUE));
+    assert!(reify_bool(IsLess::<Negation<Three>, One>::VALUE));
+    assert!(reify_bool(IsLessOrEqual::<Two, Two>::VALUE));
+    assert!(!reify_bool(
+        IsGreaterOrEqual::<Fraction<One, Three>, Fraction<One, Two>>::VALUE
+    ));
+    assert!(reify_bool(IsZero::<Prev<Next<Zero>>>::VALUE));
+    assert!(reify_bool(IsNonZero::<Fraction<One, Two>>::VALUE));
+    assert!(reify_bool(IsPositive::<Five>::VALUE));
+    assert!(!reify_bool(IsPositive::<Zero>::VALUE));
+    assert!(reify_bool(
+        IsNegative::<Fraction<Negation<One>, Two>>::VALUE
+    ));
+    assert!(reify_bool(IsNonNegative::<Zero>::VALUE));
+    assert!(!reify_bool(IsNonPositive::<One>::VALUE));
 }
 
 #[test]
 fn rpn() {
     assert_eq!(reify_i32(<rpn!(3 4 5 + *)>::VALUE), 27);
+    assert_eq!(reify_i32(<rpn!(6 4 fract 4 * int)>::VALUE), 6);
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn expr() {
+    type A = Two;
+    type B = Negation<Three>;
+
+    assert_eq!(
+        <expr!(3 * (A + 4) / B)>::VALUE,
+        <rpn!(3 (A) 4 + * (B) /)>::VALUE
+    );
+    assert_eq!(reify_i32(<expr!(3 * (A + 4) / B)>::VALUE), -6);
+    assert_eq!(reify_i32(<expr!(1 + 2 * 3 - 4)>::VALUE), 3);
+    assert_eq!(reify_i32(<expr!(10 - 4 - 3)>::VALUE), 3);
+    assert_eq!(reify_i32(<expr!(7 % 4 * 2)>::VALUE), 6);
+    assert_eq!(reify_i32(<expr!(2 ^ 1 ^ 3)>::VALUE), 2);
+    assert_eq!(reify_i32(<expr!(-A ^ 2)>::VALUE), -4);
+    assert_eq!(reify_i32(<expr!((-A) ^ 2)>::VALUE), 4);
+    assert_eq!(reify_i32(<expr!(--B)>::VALUE), -3);
+    assert_eq!(reify_i32(<expr!(12 + Next<A>)>::VALUE), 15);
+    assert_eq!(
+        reify_i32(<expr!(<A as core::ops::Neg>::Output * 2)>::VALUE),
+        -4
+    );
+    assert_eq!(reify_i32(<expr!(abs(B) + gcd(4, 6 * A))>::VALUE), 7);
+    assert_eq!(reify_i32(<expr!(lcm(A, B) + int(fract(9, 3)))>::VALUE), 9);
+    assert_eq!(
+        reify_i32_i32(<expr!(inv(fract(A, 3)) * 2 + fract(-1, 3))>::VALUE),
+        (8, 3)
+    );
+    assert_eq!(
+        core::any::type_name::<expr!(1234)>()
+            .matches("Next")
+            .count(),
+        1234
+    );
 }